@@ -0,0 +1,124 @@
+use std::{
+    fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use utils::{report, Part, Solution};
+
+/// Summary of repeated timings of the same piece of work
+#[derive(Debug, Serialize)]
+pub struct Stats {
+    #[serde(rename = "mean_ns", serialize_with = "report::as_nanos")]
+    pub mean: Duration,
+    #[serde(rename = "median_ns", serialize_with = "report::as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "min_ns", serialize_with = "report::as_nanos")]
+    pub min: Duration,
+}
+
+impl Stats {
+    fn new(mut timings: Vec<Duration>) -> Self {
+        timings.sort();
+        let mid = timings.len() / 2;
+        let median = if timings.len().is_multiple_of(2) {
+            (timings[mid - 1] + timings[mid]) / 2
+        } else {
+            timings[mid]
+        };
+        Stats {
+            mean: timings.iter().sum::<Duration>() / timings.len() as u32,
+            median,
+            min: timings[0],
+        }
+    }
+}
+
+/// Parse and solve timings of one part of one day
+#[derive(Debug, Serialize)]
+pub struct Measurement {
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// parses `input` and solves `part` `iterations` times, timing both steps separately
+pub fn measure<S: Solution>(input: &str, part: Part, iterations: u32) -> Result<Measurement> {
+    let iterations = iterations.max(1) as usize;
+    let mut parse = Vec::with_capacity(iterations);
+    let mut solve = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let data = S::parse(black_box(input))?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => S::part1(&data)?,
+            Part::Two => S::part2(&data)?,
+        };
+        solve.push(start.elapsed());
+        black_box(answer);
+    }
+    Ok(Measurement {
+        parse: Stats::new(parse),
+        solve: Stats::new(solve),
+    })
+}
+
+#[derive(Serialize)]
+pub struct Benched {
+    pub day: u8,
+    pub part: Part,
+    #[serde(flatten)]
+    pub measurement: Measurement,
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    iterations: u32,
+    results: &'a [Benched],
+}
+
+pub fn print_table(benched: &[Benched]) {
+    println!(
+        "Day  Part  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "parse mean", "median", "min", "solve mean", "median", "min"
+    );
+    for b in benched {
+        let (p, s) = (&b.measurement.parse, &b.measurement.solve);
+        println!(
+            "{:>3}  {:>4}  {:>10.2?}  {:>10.2?}  {:>10.2?}  {:>10.2?}  {:>10.2?}  {:>10.2?}",
+            b.day, b.part, p.mean, p.median, p.min, s.mean, s.median, s.min
+        );
+    }
+}
+
+pub fn write_json<P: AsRef<Path>>(path: P, iterations: u32, benched: &[Benched]) -> Result<()> {
+    let path = path.as_ref();
+    let report = serde_json::to_string_pretty(&Report {
+        iterations,
+        results: benched,
+    })?;
+    fs::write(path, report + "\n").context(format!("Could not write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_of_even_and_odd_samples() {
+        let ms = Duration::from_millis;
+        let stats = Stats::new(vec![ms(4), ms(1), ms(3), ms(8)]);
+        assert_eq!(
+            (stats.mean, stats.median, stats.min),
+            (ms(4), ms(3) + ms(1) / 2, ms(1))
+        );
+
+        let stats = Stats::new(vec![ms(5), ms(1), ms(3)]);
+        assert_eq!((stats.mean, stats.median, stats.min), (ms(3), ms(3), ms(1)));
+    }
+}
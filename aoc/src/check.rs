@@ -0,0 +1,238 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path};
+
+use anyhow::{bail, Context, Result};
+use toml::{Table, Value};
+use utils::{read_input_file, InputError, Part};
+
+use crate::days::Day;
+
+/// Name of the file in each day's directory that holds the expected answers
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Expected answers of one day, keyed by the name of the input file they belong to:
+///
+/// ```toml
+/// [input]
+/// part1 = 54304
+/// part2 = "54418"
+///
+/// [example2]
+/// part2 = 281
+/// ```
+#[derive(Debug, Default)]
+pub struct AnswerSheet(BTreeMap<String, BTreeMap<Part, String>>);
+
+impl AnswerSheet {
+    /// reads the answer sheet at `path`, a missing file is an empty sheet
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(AnswerSheet::default());
+        }
+        let content =
+            fs::read_to_string(path).context(format!("Could not read {}", path.display()))?;
+        Self::parse(&content).context(format!("Invalid answer sheet {}", path.display()))
+    }
+
+    fn parse(content: &str) -> Result<Self> {
+        let table: Table = content.parse()?;
+        let mut sheet = BTreeMap::new();
+        for (file, answers) in table {
+            let Value::Table(answers) = answers else {
+                bail!("'{file}' has to be a table of part1/part2 answers");
+            };
+            let mut parts = BTreeMap::new();
+            for (key, value) in answers {
+                let part = match key.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    k => bail!("Unknown key '{k}' in '{file}', use part1 or part2"),
+                };
+                let answer = match value {
+                    Value::Integer(n) => n.to_string(),
+                    Value::String(s) => s,
+                    v => bail!("Answer for '{file}' {key} has to be a number or string, not {v}"),
+                };
+                parts.insert(part, answer);
+            }
+            sheet.insert(file, parts);
+        }
+        Ok(AnswerSheet(sheet))
+    }
+}
+
+/// Adds `answer` for `part` of `file` to the answer sheet at `path` unless it already has an
+/// answer for it. Comments and layout of the sheet are left as they are. Returns whether the
+/// answer was added.
+pub fn record_answer<P: AsRef<Path>>(
+    path: P,
+    file: &str,
+    part: Part,
+    answer: &str,
+) -> Result<bool> {
+    let path = path.as_ref();
+    let content = if path.exists() {
+        fs::read_to_string(path).context(format!("Could not read {}", path.display()))?
+    } else {
+        String::new()
+    };
+    let Some(content) = add_answer(&content, file, part, answer)
+        .context(format!("Invalid answer sheet {}", path.display()))?
+    else {
+        return Ok(false);
+    };
+    fs::write(path, content).context(format!("Could not write {}", path.display()))?;
+    Ok(true)
+}
+
+fn add_answer(content: &str, file: &str, part: Part, answer: &str) -> Result<Option<String>> {
+    let sheet = AnswerSheet::parse(content)?;
+    if sheet
+        .0
+        .get(file)
+        .is_some_and(|parts| parts.contains_key(&part))
+    {
+        return Ok(None);
+    }
+    let value = match answer.parse::<i64>() {
+        Ok(n) => Value::Integer(n),
+        Err(_) => Value::String(answer.to_owned()),
+    };
+    let line = format!("part{part} = {value}");
+    let header = format!("[{file}]");
+
+    let mut lines: Vec<&str> = content.lines().collect();
+    match lines.iter().position(|l| l.trim() == header) {
+        Some(idx) => lines.insert(idx + 1, &line),
+        None => {
+            if lines.last().is_some_and(|l| !l.trim().is_empty()) {
+                lines.push("");
+            }
+            lines.push(&header);
+            lines.push(&line);
+        }
+    }
+    Ok(Some(lines.join("\n") + "\n"))
+}
+
+#[derive(Debug)]
+pub enum Status {
+    Pass,
+    Fail(String),
+    Missing(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail(reason) => write!(f, "FAIL ({reason})"),
+            Status::Missing(reason) => write!(f, "MISSING ({reason})"),
+        }
+    }
+}
+
+/// Result of checking one part of one day against one input file
+pub struct Checked {
+    pub day: u8,
+    pub file: String,
+    pub part: Part,
+    pub status: Status,
+}
+
+/// the inputs and examples lying around in the day's directory
+fn input_files(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+    entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| name == "input" || name.starts_with("example"))
+        .collect()
+}
+
+/// re-solves every input of `day` that has expected answers and compares the results
+pub fn check_day(day: &Day) -> Result<Vec<Checked>> {
+    let dir = day.dir();
+    let sheet = AnswerSheet::load(dir.join(ANSWERS_FILE))?;
+
+    let mut files: Vec<String> = sheet.0.keys().cloned().collect();
+    files.extend(input_files(&dir));
+    files.sort();
+    files.dedup();
+
+    let mut res = vec![];
+    for file in files {
+        let checked = |part, status| Checked {
+            day: day.nr,
+            file: file.clone(),
+            part,
+            status,
+        };
+        let Some(expected) = sheet.0.get(&file) else {
+            for part in Part::ALL {
+                res.push(checked(part, Status::Missing("no expected answer".into())));
+            }
+            continue;
+        };
+        let input = read_input_file(dir.join(&file));
+        for (&part, answer) in expected {
+            let status = match &input {
+                Ok(data) => match (day.solve)(data, part) {
+                    Ok(got) if &got.to_string() == answer => Status::Pass,
+                    Ok(got) => Status::Fail(format!("expected {answer}, got {got}")),
+                    Err(err) => Status::Fail(format!("{err:#}")),
+                },
+                Err(err @ InputError::Missing(_)) => Status::Missing(err.to_string()),
+                Err(err) => Status::Fail(err.to_string()),
+            };
+            res.push(checked(part, status));
+        }
+    }
+    Ok(res)
+}
+
+pub fn print_report(checked: &[Checked]) {
+    let width = checked
+        .iter()
+        .map(|c| c.file.len())
+        .max()
+        .unwrap_or_default()
+        .max("File".len());
+    println!("Day  Part  {:width$}  Status", "File");
+    for c in checked {
+        println!(
+            "{:>3}  {:>4}  {:width$}  {}",
+            c.day, c.part, c.file, c.status
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_answers_without_touching_the_rest() {
+        let sheet = "# expected answers\n\n[input]\npart1 = 54304\n";
+        let sheet = add_answer(sheet, "example1", Part::One, "142")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            sheet,
+            "# expected answers\n\n[input]\npart1 = 54304\n\n[example1]\npart1 = 142\n"
+        );
+        let sheet = add_answer(&sheet, "input", Part::Two, "a-b")
+            .unwrap()
+            .unwrap();
+        assert!(sheet.contains("[input]\npart2 = \"a-b\"\npart1 = 54304\n"));
+        assert_eq!(
+            add_answer(&sheet, "example1", Part::One, "7").unwrap(),
+            None
+        );
+
+        let parsed = AnswerSheet::parse(&sheet).unwrap();
+        assert_eq!(parsed.0["input"][&Part::Two], "a-b");
+        assert_eq!(parsed.0["example1"][&Part::One], "142");
+    }
+}
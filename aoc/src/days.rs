@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use utils::{day_dir, report::Report, Answer, Part, Solution};
+
+use crate::bench::{self, Measurement};
+
+/// A day of the calendar as the runner sees it
+pub struct Day {
+    pub nr: u8,
+    pub solve: fn(&str, Part) -> Result<Answer>,
+    pub report: fn(&str, Part) -> Result<Report>,
+    pub bench: fn(&str, Part, u32) -> Result<Measurement>,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Self {
+        Day {
+            nr: S::DAY,
+            solve: S::solve,
+            report: Report::solve::<S>,
+            bench: bench::measure::<S>,
+        }
+    }
+
+    /// the crate directory holding the inputs and expected answers of this day
+    pub fn dir(&self) -> PathBuf {
+        day_dir(self.nr)
+    }
+}
+
+/// every day that has been solved so far, in calendar order
+pub const DAYS: &[Day] = &[
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::of::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day08::Day08>(),
+    Day::of::<day09::Day09>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+];
+
+pub fn get(nr: u8) -> Result<&'static Day> {
+    DAYS.iter()
+        .find(|day| day.nr == nr)
+        .context(format!("Day {nr} has not been solved yet"))
+}
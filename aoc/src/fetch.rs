@@ -0,0 +1,113 @@
+use std::{fs, io::Read, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use utils::day_dir;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// File in the workspace root the session cookie is read from if `AOC_SESSION` is not set
+pub const SESSION_FILE: &str = ".aoc_session";
+
+const USER_AGENT: &str = "github.com/teschmitt/Advent-of-Code-2023 input fetcher";
+
+/// Talks to the Advent of Code website (or anything pretending to be it) with a logged in session
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+/// What `Client::fetch_input` did to get the input
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    /// the input had been downloaded before and was left alone
+    Cached(PathBuf),
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: String) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.trim().to_owned(),
+        }
+    }
+
+    /// Uses the session given on the command line or in `AOC_SESSION`, then falls back to the
+    /// session file
+    pub fn from_session(base_url: &str, session: Option<String>) -> Result<Self> {
+        let session = match session {
+            Some(session) => session,
+            None => fs::read_to_string(SESSION_FILE).context(format!(
+                "No session cookie found, set AOC_SESSION or put it into {SESSION_FILE}"
+            ))?,
+        };
+        if session.trim().is_empty() {
+            bail!("The session cookie is empty");
+        }
+        Ok(Client::new(base_url, session))
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        let url = format!("{}{path}", self.base_url);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(code, _) => {
+                    anyhow::anyhow!(
+                        "{url} answered with status {code}, is the session still valid?"
+                    )
+                }
+                err => anyhow::anyhow!("Could not reach {url}: {err}"),
+            })?;
+        let mut body = String::new();
+        response
+            .into_reader()
+            .read_to_string(&mut body)
+            .context(format!("Could not read response from {url}"))?;
+        Ok(body)
+    }
+
+    /// Downloads the puzzle input of `day` to `dayNN/input` unless it is already there
+    pub fn fetch_input(&self, day: u8) -> Result<Fetched> {
+        let path = day_file(day, "input")?;
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let input = self.get(&format!("/2023/day/{day}/input"))?;
+        fs::write(&path, input).context(format!("Could not write {}", path.display()))?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// The description of `day` as HTML, with part two in it once part one is solved
+    pub fn puzzle_page(&self, day: u8) -> Result<String> {
+        in_calendar(day)?;
+        self.get(&format!("/2023/day/{day}"))
+    }
+}
+
+/// the path of `name` in the directory of `day`, which has to exist already
+fn day_file(day: u8, name: &str) -> Result<PathBuf> {
+    Ok(existing_day_dir(day)?.join(name))
+}
+
+/// the directory of `day`, an error if the day is not in the calendar or not created yet
+pub fn existing_day_dir(day: u8) -> Result<PathBuf> {
+    in_calendar(day)?;
+    let dir = day_dir(day);
+    if !dir.is_dir() {
+        bail!(
+            "Directory {} does not exist, create the day first",
+            dir.display()
+        );
+    }
+    Ok(dir)
+}
+
+pub fn in_calendar(day: u8) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("There is no day {day} in the calendar");
+    }
+    Ok(())
+}
@@ -0,0 +1,313 @@
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Args, Parser, Subcommand};
+use utils::{
+    day_dir,
+    report::{self, Format, Report},
+    Input, InputError, InputSource, Part,
+};
+
+mod bench;
+mod check;
+mod days;
+mod fetch;
+mod new;
+mod puzzle;
+
+use days::{Day, DAYS};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day or, with --all, every day there is a solution for
+    Run {
+        /// Day to solve
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        /// Part to solve, both parts are solved if left out
+        part: Option<Part>,
+        /// Puzzle input file or - for stdin, defaults to dayNN/input
+        #[arg(long)]
+        input: Option<InputSource>,
+        /// Read dayNN/exampleN instead of the puzzle input, dayNN/example1 if N is left out
+        #[arg(
+            long,
+            value_name = "N",
+            num_args = 0..=1,
+            default_missing_value = "1",
+            conflicts_with = "input"
+        )]
+        example: Option<u8>,
+        /// Solve all days with their default inputs
+        #[arg(long, conflicts_with_all = ["day", "part", "input"])]
+        all: bool,
+        /// How answers are printed: plain, json or tsv
+        #[arg(long, default_value = "plain")]
+        format: Format,
+    },
+    /// Time parsing and solving over many iterations
+    Bench {
+        /// Day to benchmark, all days with an input are benchmarked if left out
+        day: Option<u8>,
+        /// Part to benchmark, both parts are benchmarked if left out
+        part: Option<Part>,
+        /// Puzzle input file or - for stdin, defaults to dayNN/input
+        #[arg(long, requires = "day")]
+        input: Option<InputSource>,
+        /// Read dayNN/exampleN instead of the puzzle input, dayNN/example1 if N is left out
+        #[arg(
+            long,
+            value_name = "N",
+            num_args = 0..=1,
+            default_missing_value = "1",
+            conflicts_with = "input"
+        )]
+        example: Option<u8>,
+        /// How often each part is parsed and solved
+        #[arg(short = 'n', long, default_value_t = 100)]
+        iterations: u32,
+        /// Also write the results as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+    },
+    /// Create dayNN from the template and add it to the runner, existing days are never touched
+    New {
+        /// Day to create
+        day: u8,
+        /// Also download the puzzle input and the examples of the puzzle description
+        #[arg(long)]
+        fetch: bool,
+        #[command(flatten)]
+        site: Site,
+    },
+    /// Download the puzzle input of a day to dayNN/input, existing inputs are never replaced
+    Fetch {
+        /// Day to download the input for
+        day: u8,
+        #[command(flatten)]
+        site: Site,
+    },
+    /// Save the examples of a puzzle description to dayNN/exampleN and record their answers
+    Examples {
+        /// Day the puzzle description belongs to
+        day: u8,
+        /// Saved puzzle page to read instead of downloading it
+        #[arg(long)]
+        html: Option<PathBuf>,
+        #[command(flatten)]
+        site: Site,
+    },
+    /// Re-solve every input that has expected answers in dayNN/answers.toml and compare
+    Check {
+        /// Only check this day
+        day: Option<u8>,
+    },
+}
+
+/// How to reach the Advent of Code website
+#[derive(Args)]
+struct Site {
+    /// Session cookie of a logged in browser, read from .aoc_session if not set
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    /// Where to download from
+    #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+impl Site {
+    fn client(self) -> Result<fetch::Client> {
+        fetch::Client::from_session(&self.base_url, self.session)
+    }
+}
+
+/// saves the examples found in the puzzle page of `day`
+fn save_examples(day: u8, html: &str) -> Result<()> {
+    let examples = puzzle::examples(html);
+    if examples.is_empty() {
+        println!("No examples found in the description of day {day}");
+    }
+    for saved in puzzle::save_examples(&day_dir(day), &examples)? {
+        println!("{saved}");
+    }
+    Ok(())
+}
+
+fn print_fetched(fetched: fetch::Fetched) {
+    match fetched {
+        fetch::Fetched::Downloaded(path) => println!("Downloaded {}", path.display()),
+        fetch::Fetched::Cached(path) => {
+            println!("{} already exists, not downloading again", path.display())
+        }
+    }
+}
+
+/// an explicit `--input` wins over `--example`, without either the default input is read
+fn input_source(input: Option<InputSource>, example: Option<u8>) -> InputSource {
+    match (input, example) {
+        (Some(input), _) => input,
+        (None, Some(nr)) => InputSource::Example(nr),
+        (None, None) => InputSource::Default,
+    }
+}
+
+/// solves the given parts of `day`, failures are reported on stderr and counted
+fn solve_day(
+    day: &Day,
+    parts: &[Part],
+    input: Result<Input, InputError>,
+    reports: &mut Vec<Report>,
+) -> usize {
+    let mut failed = 0;
+    for &part in parts {
+        let res = match &input {
+            Ok(data) => (day.report)(data, part),
+            Err(err) => Err(anyhow!("{err}")),
+        };
+        match res {
+            Ok(report) => reports.push(report),
+            Err(err) => {
+                eprintln!("Day {} part {part} failed: {err:#}", day.nr);
+                failed += 1;
+            }
+        }
+    }
+    failed
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<()> {
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            example,
+            all,
+            format,
+        } => {
+            let source = input_source(input, example);
+            let parts = part.map_or(Part::ALL.to_vec(), |p| vec![p]);
+            let mut reports = vec![];
+            let failed: usize = if all {
+                DAYS.iter()
+                    .map(|day| solve_day(day, &parts, source.read(day.nr), &mut reports))
+                    .sum()
+            } else {
+                // clap makes sure a day is passed whenever --all is not
+                let day = days::get(day.unwrap_or_default())?;
+                let data = source.read(day.nr)?;
+                solve_day(day, &parts, Ok(data), &mut reports)
+            };
+
+            report::print(&reports, format);
+            if failed > 0 {
+                bail!(
+                    "{failed} of {} parts could not be solved",
+                    failed + reports.len()
+                );
+            }
+        }
+        Command::Bench {
+            day,
+            part,
+            input,
+            example,
+            iterations,
+            json,
+        } => {
+            let source = input_source(input, example);
+            let parts = part.map_or(Part::ALL.to_vec(), |p| vec![p]);
+            let mut benched = vec![];
+            if let Some(nr) = day {
+                let day = days::get(nr)?;
+                let data = source.read(day.nr)?;
+                for &part in &parts {
+                    benched.push(bench::Benched {
+                        day: day.nr,
+                        part,
+                        measurement: (day.bench)(&data, part, iterations)?,
+                    });
+                }
+            } else {
+                for day in DAYS {
+                    let data = match source.read(day.nr) {
+                        Ok(data) => data,
+                        Err(InputError::Missing(_)) => {
+                            eprintln!("Skipping day {}, it has no input", day.nr);
+                            continue;
+                        }
+                        Err(err) => return Err(err.into()),
+                    };
+                    for &part in &parts {
+                        benched.push(bench::Benched {
+                            day: day.nr,
+                            part,
+                            measurement: (day.bench)(&data, part, iterations)?,
+                        });
+                    }
+                }
+            }
+
+            bench::print_table(&benched);
+            if let Some(path) = json {
+                bench::write_json(path, iterations, &benched)?;
+            }
+        }
+        Command::New { day, fetch, site } => {
+            let dir = new::new_day(day)?;
+            println!("Created {} and added it to the runner", dir.display());
+            if fetch {
+                let client = site.client()?;
+                print_fetched(client.fetch_input(day)?);
+                save_examples(day, &client.puzzle_page(day)?)?;
+            }
+        }
+        Command::Examples { day, html, site } => {
+            fetch::existing_day_dir(day)?;
+            let html = match html {
+                Some(path) => fs::read_to_string(&path)
+                    .context(format!("Could not read {}", path.display()))?,
+                None => site.client()?.puzzle_page(day)?,
+            };
+            save_examples(day, &html)?;
+        }
+        Command::Fetch { day, site } => print_fetched(site.client()?.fetch_input(day)?),
+        Command::Check { day } => {
+            let to_check = match day {
+                Some(nr) => vec![days::get(nr)?],
+                None => DAYS.iter().collect(),
+            };
+            let mut checked = vec![];
+            for day in to_check {
+                checked.extend(check::check_day(day)?);
+            }
+
+            check::print_report(&checked);
+            let failed = checked
+                .iter()
+                .filter(|c| matches!(c.status, check::Status::Fail(_)))
+                .count();
+            if failed > 0 {
+                bail!("{failed} of {} checks failed", checked.len());
+            }
+        }
+    }
+    Ok(())
+}
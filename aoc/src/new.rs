@@ -0,0 +1,193 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use utils::day_dir;
+
+use crate::fetch;
+
+/// Directory the new days are copied from
+pub const TEMPLATE_DIR: &str = "template";
+
+/// Build artifacts that may lie around in the template but don't belong into a new day
+const SKIPPED: &[&str] = &["Cargo.lock", "target"];
+
+const RUNNER_MANIFEST: &str = "aoc/Cargo.toml";
+const RUNNER_DAYS: &str = "aoc/src/days.rs";
+
+/// Creates `dayNN` from the template and registers it with the runner, an existing day is
+/// never touched. Nothing is left behind if any step fails.
+pub fn new_day(day: u8) -> Result<PathBuf> {
+    fetch::in_calendar(day)?;
+    let dir = day_dir(day);
+    if dir.exists() {
+        bail!("{} already exists, not overwriting it", dir.display());
+    }
+    // the runner files are read and updated in memory before anything is created
+    let runner = registered(day)?;
+    if let Err(err) = copy_template(Path::new(TEMPLATE_DIR), &dir, day) {
+        let _ = fs::remove_dir_all(&dir);
+        return Err(err.context(format!("Could not create {}", dir.display())));
+    }
+    if let Err(err) = write_all(&runner) {
+        let _ = fs::remove_dir_all(&dir);
+        return Err(err);
+    }
+    Ok(dir)
+}
+
+/// replaces the placeholders of the template with the names of `day`
+fn rename(text: &str, day: u8) -> String {
+    text.replace("testday", &format!("day{day:02}"))
+        .replace("dayxx", &format!("day{day:02}"))
+        .replace("Dayxx", &format!("Day{day:02}"))
+        .replace("DAY: u8 = 0", &format!("DAY: u8 = {day}"))
+}
+
+fn copy_template(from: &Path, to: &Path, day: u8) -> Result<()> {
+    fs::create_dir(to)?;
+    for entry in fs::read_dir(from).context(format!("Could not read {}", from.display()))? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if SKIPPED.contains(&name.as_str()) {
+            continue;
+        }
+        let target = to.join(rename(&name, day));
+        if entry.file_type()?.is_dir() {
+            copy_template(&entry.path(), &target, day)?;
+        } else {
+            let content = fs::read_to_string(entry.path())?;
+            fs::write(target, rename(&content, day))?;
+        }
+    }
+    Ok(())
+}
+
+/// A file with the lines it has now and the ones it gets
+struct Update<'a> {
+    path: &'a Path,
+    before: String,
+    after: String,
+}
+
+/// the runner's manifest and list of days with `day` added
+fn registered(day: u8) -> Result<[Update<'static>; 2]> {
+    let name = format!("day{day:02}");
+    Ok([
+        register_in(
+            RUNNER_MANIFEST,
+            "day",
+            &format!("{name} = {{ path = \"../{name}\" }}"),
+        )?,
+        register_in(
+            RUNNER_DAYS,
+            "    Day::of::<day",
+            &format!("    Day::of::<{name}::Day{day:02}>(),"),
+        )?,
+    ])
+}
+
+fn register_in(path: &'static str, prefix: &str, line: &str) -> Result<Update<'static>> {
+    let before = fs::read_to_string(path).context(format!("Could not read {path}"))?;
+    let after = insert_sorted(&before, prefix, line)
+        .context(format!("Could not register the day in {path}"))?;
+    Ok(Update {
+        path: Path::new(path),
+        before,
+        after,
+    })
+}
+
+/// writes every update or, if one of them fails, puts back the files written before it
+fn write_all(updates: &[Update]) -> Result<()> {
+    for (idx, update) in updates.iter().enumerate() {
+        if let Err(err) = fs::write(update.path, &update.after) {
+            for written in &updates[..idx] {
+                let _ = fs::write(written.path, &written.before);
+            }
+            return Err(err).context(format!("Could not write {}", update.path.display()));
+        }
+    }
+    Ok(())
+}
+
+/// Inserts `line` into the block of lines starting with `prefix`, keeping it sorted. Nothing
+/// changes if the line is already there.
+fn insert_sorted(content: &str, prefix: &str, line: &str) -> Result<String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let block: Vec<usize> = (0..lines.len())
+        .filter(|&idx| lines[idx].starts_with(prefix))
+        .collect();
+    let Some(&last) = block.last() else {
+        bail!("No lines starting with '{prefix}' found");
+    };
+    if !lines.contains(&line) {
+        let at = block
+            .into_iter()
+            .find(|&idx| lines[idx] > line)
+            .unwrap_or(last + 1);
+        lines.insert(at, line);
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renames_template_placeholders() {
+        assert_eq!(
+            rename("use dayxx::Dayxx;\nconst DAY: u8 = 0;", 5),
+            "use day05::Day05;\nconst DAY: u8 = 5;"
+        );
+        assert_eq!(rename("dayxx_part2.rs", 12), "day12_part2.rs");
+        assert_eq!(rename("name = \"testday\"", 12), "name = \"day12\"");
+    }
+
+    #[test]
+    fn restores_written_files_when_a_write_fails() {
+        let dir = std::env::temp_dir().join(format!("aoc-new-restore-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let written = dir.join("Cargo.toml");
+        fs::write(&written, "before").unwrap();
+        let missing = dir.join("no/such/days.rs");
+
+        let err = write_all(&[
+            Update {
+                path: &written,
+                before: "before".into(),
+                after: "after".into(),
+            },
+            Update {
+                path: &missing,
+                before: String::new(),
+                after: "after".into(),
+            },
+        ])
+        .unwrap_err();
+        assert!(err.to_string().contains("days.rs"));
+        assert_eq!(fs::read_to_string(&written).unwrap(), "before");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn inserts_days_in_order() {
+        let manifest = "[dependencies]\nanyhow = \"1\"\nday04 = 4\nday06 = 6\n";
+        assert_eq!(
+            insert_sorted(manifest, "day", "day05 = 5").unwrap(),
+            "[dependencies]\nanyhow = \"1\"\nday04 = 4\nday05 = 5\nday06 = 6\n"
+        );
+        assert_eq!(
+            insert_sorted(manifest, "day", "day12 = 12").unwrap(),
+            "[dependencies]\nanyhow = \"1\"\nday04 = 4\nday06 = 6\nday12 = 12\n"
+        );
+        assert_eq!(
+            insert_sorted(manifest, "day", "day06 = 6").unwrap(),
+            manifest
+        );
+        assert!(insert_sorted(manifest, "nope", "day05 = 5").is_err());
+    }
+}
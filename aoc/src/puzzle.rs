@@ -0,0 +1,281 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use utils::Part;
+
+use crate::check::{self, ANSWERS_FILE};
+
+/// What the description of one part of a puzzle gives away
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PartDescription {
+    /// the `<pre><code>` blocks introduced by a paragraph mentioning an example
+    pub examples: Vec<String>,
+    /// the last emphasized `<code>` of the description, which is where the answer for the
+    /// example is given away
+    pub answer: Option<String>,
+}
+
+/// An example input together with the answers the description gives for it
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answers: BTreeMap<Part, String>,
+}
+
+/// Reads the description of every part that is on the puzzle page, part two is only there
+/// once part one is solved
+pub fn describe(html: &str) -> Vec<PartDescription> {
+    const ARTICLE: &str = "<article class=\"day-desc\">";
+    html.split(ARTICLE)
+        .skip(1)
+        .map(|article| {
+            let article = article.split("</article>").next().unwrap_or_default();
+            PartDescription {
+                examples: examples_in(article),
+                answer: answer_in(article),
+            }
+        })
+        .collect()
+}
+
+/// The distinct examples of the puzzle page in order of appearance. The answer of a part is
+/// attributed to the last example shown in that part, or to the last one before it if the
+/// part reuses an earlier example.
+pub fn examples(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = vec![];
+    for (part, description) in Part::ALL.into_iter().zip(describe(html)) {
+        for input in description.examples {
+            if !examples.iter().any(|ex| ex.input == input) {
+                examples.push(Example {
+                    input,
+                    answers: BTreeMap::new(),
+                });
+            }
+        }
+        if let (Some(answer), Some(example)) = (description.answer, examples.last_mut()) {
+            example.answers.insert(part, answer);
+        }
+    }
+    examples
+}
+
+fn examples_in(article: &str) -> Vec<String> {
+    const PRE: &str = "<pre><code>";
+    let mut examples = vec![];
+    let mut rest = article;
+    while let Some(start) = rest.find(PRE) {
+        let intro = rest[..start].rsplit("<p>").next().unwrap_or_default();
+        rest = &rest[start + PRE.len()..];
+        let Some(end) = rest.find("</code></pre>") else {
+            break;
+        };
+        if strip_tags(intro).to_lowercase().contains("example") {
+            examples.push(unescape(&strip_tags(&rest[..end])));
+        }
+        rest = &rest[end..];
+    }
+    examples
+}
+
+fn answer_in(article: &str) -> Option<String> {
+    article
+        .match_indices("<code><em>")
+        .filter(|(idx, _)| !article[..*idx].ends_with("<pre>"))
+        .filter_map(|(idx, tag)| {
+            let answer = &article[idx + tag.len()..];
+            Some(unescape(&answer[..answer.find("</em></code>")?]))
+        })
+        .last()
+}
+
+/// drops markup like the `<em>` used to highlight parts of an example
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            ch if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// What `save_examples` did with an example or answer
+#[derive(Debug, PartialEq, Eq)]
+pub enum Saved {
+    Example(PathBuf),
+    /// there already is a different example under this name, it and its answers are left alone
+    Kept(PathBuf),
+    Answer {
+        file: String,
+        part: Part,
+        answer: String,
+    },
+}
+
+impl fmt::Display for Saved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Saved::Example(path) => write!(f, "Saved {}", path.display()),
+            Saved::Kept(path) => write!(f, "{} differs, leaving it alone", path.display()),
+            Saved::Answer { file, part, answer } => {
+                write!(f, "Expecting {answer} for part {part} of {file}")
+            }
+        }
+    }
+}
+
+/// Writes the examples to `example1`, `example2`, … in `dir` and records their answers in the
+/// answer sheet. Examples and answers that are already there are not touched.
+pub fn save_examples(dir: &Path, examples: &[Example]) -> Result<Vec<Saved>> {
+    let mut saved = vec![];
+    for (nr, example) in (1..).zip(examples) {
+        let file = format!("example{nr}");
+        let path = dir.join(&file);
+        if path.exists() {
+            let existing =
+                fs::read_to_string(&path).context(format!("Could not read {}", path.display()))?;
+            if existing.trim_end() != example.input.trim_end() {
+                saved.push(Saved::Kept(path));
+                continue;
+            }
+        } else {
+            fs::write(&path, &example.input)
+                .context(format!("Could not write {}", path.display()))?;
+            saved.push(Saved::Example(path));
+        }
+
+        for (&part, answer) in &example.answers {
+            if check::record_answer(dir.join(ANSWERS_FILE), &file, part, answer)? {
+                saved.push(Saved::Answer {
+                    file: file.clone(),
+                    part,
+                    answer: answer.clone(),
+                });
+            }
+        }
+    }
+    Ok(saved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY01: &str = include_str!("../tests/fixtures/day01.html");
+    const DAY03: &str = include_str!("../tests/fixtures/day03.html");
+    const DAY08: &str = include_str!("../tests/fixtures/day08.html");
+
+    #[test]
+    fn describes_both_parts() {
+        let parts = describe(DAY01);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(
+            parts[0].examples,
+            ["1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n"]
+        );
+        assert_eq!(parts[0].answer.as_deref(), Some("142"));
+        assert_eq!(parts[1].examples.len(), 1);
+        assert!(parts[1].examples[0].starts_with("two1nine\n"));
+        assert_eq!(parts[1].answer.as_deref(), Some("281"));
+    }
+
+    #[test]
+    fn strips_highlighting_from_examples() {
+        let parts = describe(DAY03);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].examples.len(), 1);
+        assert!(parts[0].examples[0].starts_with("467..114..\n...*......\n"));
+        assert_eq!(parts[0].answer.as_deref(), Some("4361"));
+        assert_eq!(parts[1].examples, Vec::<String>::new());
+        assert_eq!(parts[1].answer.as_deref(), Some("467835"));
+    }
+
+    #[test]
+    fn attributes_answers_to_examples() {
+        let examples = examples(DAY01);
+        assert_eq!(examples.len(), 2);
+        assert_eq!(
+            examples[0].answers,
+            BTreeMap::from([(Part::One, "142".to_owned())])
+        );
+        assert_eq!(
+            examples[1].answers,
+            BTreeMap::from([(Part::Two, "281".to_owned())])
+        );
+
+        // part two of day 3 reuses the example of part one
+        let examples = super::examples(DAY03);
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].answers.len(), 2);
+
+        // only the last of the two examples of day 8 part one gets an answer
+        let examples = super::examples(DAY08);
+        assert_eq!(examples.len(), 3);
+        assert!(examples[0].answers.is_empty());
+        assert_eq!(
+            examples[1].answers,
+            BTreeMap::from([(Part::One, "6".to_owned())])
+        );
+        assert_eq!(
+            examples[2].answers,
+            BTreeMap::from([(Part::Two, "6".to_owned())])
+        );
+    }
+
+    #[test]
+    fn ignores_blocks_that_are_no_example() {
+        let html =
+            "<article class=\"day-desc\"><p>For example:</p>\n<pre><code>7-F7-\n</code></pre>\
+                    <p>Here are the distances:</p>\n<pre><code>..45.\n</code></pre>\
+                    <p>The answer is <code><em>8</em></code>.</p></article>";
+        assert_eq!(
+            describe(html),
+            [PartDescription {
+                examples: vec!["7-F7-\n".into()],
+                answer: Some("8".into()),
+            }]
+        );
+    }
+
+    #[test]
+    fn saves_examples_and_answers_once() {
+        let dir = std::env::temp_dir().join(format!("aoc-puzzle-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let saved = save_examples(&dir, &examples(DAY01)).unwrap();
+        assert_eq!(saved.len(), 4);
+        assert_eq!(
+            fs::read_to_string(dir.join("example2")).unwrap(),
+            examples(DAY01)[1].input
+        );
+        let sheet = fs::read_to_string(dir.join(ANSWERS_FILE)).unwrap();
+        assert!(sheet.contains("[example1]\npart1 = 142\n"));
+        assert!(sheet.contains("[example2]\npart2 = 281\n"));
+
+        assert_eq!(save_examples(&dir, &examples(DAY01)).unwrap(), []);
+        fs::write(dir.join("example1"), "something else").unwrap();
+        assert_eq!(
+            save_examples(&dir, &examples(DAY01)).unwrap(),
+            [Saved::Kept(dir.join("example1"))]
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
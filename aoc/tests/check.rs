@@ -0,0 +1,22 @@
+use std::{path::PathBuf, process::Command};
+
+#[test]
+fn every_day_passes_its_recorded_answers() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("check")
+        .current_dir(root)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{output:?}");
+
+    let table = String::from_utf8_lossy(&output.stdout);
+    for day in 1..=11 {
+        assert!(
+            table
+                .lines()
+                .any(|line| line.starts_with(&format!("{day:>3} ")) && line.ends_with("PASS")),
+            "day {day} has no passing check:\n{table}"
+        );
+    }
+}
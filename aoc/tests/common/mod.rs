@@ -0,0 +1,56 @@
+// every test crate uses only some of the helpers
+#![allow(dead_code)]
+
+use std::{
+    env, fs,
+    io::{BufRead, BufReader, Write},
+    net::TcpListener,
+    path::PathBuf,
+    thread::{self, JoinHandle},
+};
+
+/// A fresh scratch directory named after the test crate and `name`, with copies of `files` of
+/// the real workspace and the empty directories `dirs`
+pub fn workspace(test: &str, name: &str, files: &[&str], dirs: &[&str]) -> PathBuf {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+    let dir = env::temp_dir().join(format!("aoc-{test}-{}-{name}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    for file in files {
+        fs::create_dir_all(dir.join(file).parent().unwrap()).unwrap();
+        fs::copy(root.join(file), dir.join(file)).unwrap();
+    }
+    for sub in dirs {
+        fs::create_dir_all(dir.join(sub)).unwrap();
+    }
+    dir
+}
+
+/// Stands in for the Advent of Code website: answers one request per given status and body,
+/// in order, and hands back the request lines and headers of every request
+pub fn serve(
+    responses: Vec<(&'static str, &'static str)>,
+) -> (String, JoinHandle<Vec<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let mut requests = vec![];
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let head = BufReader::new(stream.try_clone().unwrap())
+                .lines()
+                .map(|line| line.unwrap())
+                .take_while(|line| !line.is_empty())
+                .collect();
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            requests.push(head);
+        }
+        requests
+    });
+    (base_url, handle)
+}
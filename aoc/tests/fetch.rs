@@ -0,0 +1,99 @@
+use std::{
+    fs,
+    path::PathBuf,
+    process::{Command, Output},
+};
+
+mod common;
+
+/// a scratch directory with an empty day03 crate directory
+fn workspace(name: &str) -> PathBuf {
+    common::workspace("fetch", name, &[], &["day03"])
+}
+
+fn fetch(dir: &PathBuf, base_url: &str, day: &str) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["fetch", day])
+        .current_dir(dir)
+        .env("AOC_BASE_URL", base_url)
+        .env("AOC_SESSION", "53cr3t")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn downloads_input_once() {
+    let dir = workspace("once");
+    let (base_url, server) = common::serve(vec![("200 OK", "467..114..\n...*......\n")]);
+
+    let output = fetch(&dir, &base_url, "3");
+    assert!(output.status.success(), "{output:?}");
+    let head = &server.join().unwrap()[0];
+    assert_eq!(head[0], "GET /2023/day/3/input HTTP/1.1");
+    assert!(head.iter().any(|h| h == "Cookie: session=53cr3t"));
+    assert_eq!(
+        fs::read_to_string(dir.join("day03/input")).unwrap(),
+        "467..114..\n...*......\n"
+    );
+
+    // nobody is listening anymore, so this only works if nothing is requested
+    let output = fetch(&dir, &base_url, "3");
+    assert!(output.status.success(), "{output:?}");
+    assert!(String::from_utf8_lossy(&output.stdout).contains("already exists"));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn failed_download_leaves_no_input() {
+    let dir = workspace("failed");
+    let (base_url, server) = common::serve(vec![("400 Bad Request", "Please log in")]);
+
+    let output = fetch(&dir, &base_url, "3");
+    server.join().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("status 400"));
+    assert!(!dir.join("day03/input").exists());
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn refuses_days_without_a_directory() {
+    let dir = workspace("nodir");
+
+    let output = fetch(&dir, "http://127.0.0.1:9", "4");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("day04 does not exist"));
+
+    // saving examples from a local page checks the day the same way
+    fs::write(dir.join("page.html"), "").unwrap();
+    for (day, error) in [("4", "day04 does not exist"), ("26", "no day 26")] {
+        let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["examples", day, "--html", "page.html"])
+            .current_dir(&dir)
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains(error));
+    }
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn needs_a_session() {
+    let dir = workspace("nosession");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["fetch", "3"])
+        .current_dir(&dir)
+        .env("AOC_BASE_URL", "http://127.0.0.1:9")
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No session cookie found"));
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,125 @@
+use std::{fs, path::PathBuf, process::Command};
+
+mod common;
+
+const PUZZLE_PAGE: &str = "<article class=\"day-desc\"><p>For example:</p>\n\
+                           <pre><code>0 3 6 9 12 15\n1 3 6 10 15 21\n</code></pre>\
+                           <p>The sum is <code><em>46</em></code>.</p></article>";
+
+/// the parts of the workspace `aoc new` reads and writes
+const RUNNER_FILES: &[&str] = &[
+    "aoc/Cargo.toml",
+    "aoc/src/days.rs",
+    "template/Cargo.toml",
+    "template/answers.toml",
+    "template/src/lib.rs",
+    "template/src/bin/dayxx_part1.rs",
+    "template/src/bin/dayxx_part2.rs",
+];
+
+fn workspace(name: &str) -> PathBuf {
+    common::workspace("new", name, RUNNER_FILES, &[])
+}
+
+fn new(dir: &PathBuf, args: &[&str], base_url: &str) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .arg("new")
+        .args(args)
+        .current_dir(dir)
+        .env("AOC_BASE_URL", base_url)
+        .env("AOC_SESSION", "53cr3t")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn creates_and_registers_a_day() {
+    let dir = workspace("create");
+
+    let output = new(&dir, &["24"], "http://127.0.0.1:9");
+    assert!(output.status.success(), "{output:?}");
+
+    let lib = fs::read_to_string(dir.join("day24/src/lib.rs")).unwrap();
+    assert!(lib.contains("pub struct Day24;") && lib.contains("const DAY: u8 = 24;"));
+    let bin = fs::read_to_string(dir.join("day24/src/bin/day24_part2.rs")).unwrap();
+    assert!(bin.contains("use day24::Day24;"));
+    let manifest = fs::read_to_string(dir.join("day24/Cargo.toml")).unwrap();
+    assert!(manifest.contains("name = \"day24\""));
+    assert!(!dir.join("day24/input").exists());
+
+    let runner = fs::read_to_string(dir.join("aoc/Cargo.toml")).unwrap();
+    assert!(runner.contains("day24 = { path = \"../day24\" }"));
+    let days = fs::read_to_string(dir.join("aoc/src/days.rs")).unwrap();
+    assert!(days.contains("    Day::of::<day24::Day24>(),\n];"));
+
+    // a second run must not touch the day that is there now
+    fs::write(dir.join("day24/src/lib.rs"), "solved").unwrap();
+    let output = new(&dir, &["24"], "http://127.0.0.1:9");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("already exists"));
+    assert_eq!(
+        fs::read_to_string(dir.join("day24/src/lib.rs")).unwrap(),
+        "solved"
+    );
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn leaves_nothing_behind_when_failing() {
+    let dir = workspace("fail");
+    let days = fs::read_to_string(dir.join("aoc/src/days.rs")).unwrap();
+
+    // no list of days to add to
+    fs::write(
+        dir.join("aoc/src/days.rs"),
+        "pub const DAYS: &[Day] = &[];\n",
+    )
+    .unwrap();
+    let output = new(&dir, &["12"], "http://127.0.0.1:9");
+    assert!(!output.status.success());
+    assert!(!dir.join("day12").exists());
+
+    // no template to copy
+    fs::write(dir.join("aoc/src/days.rs"), &days).unwrap();
+    fs::rename(dir.join("template"), dir.join("moved")).unwrap();
+    let output = new(&dir, &["12"], "http://127.0.0.1:9");
+    assert!(!output.status.success());
+    assert!(!dir.join("day12").exists());
+    assert_eq!(
+        fs::read_to_string(dir.join("aoc/src/days.rs")).unwrap(),
+        days
+    );
+
+    // with everything back in place the day can be created after all
+    fs::rename(dir.join("moved"), dir.join("template")).unwrap();
+    let output = new(&dir, &["12"], "http://127.0.0.1:9");
+    assert!(output.status.success(), "{output:?}");
+    assert!(dir.join("day12/src/lib.rs").exists());
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn fetches_input_and_example() {
+    let dir = workspace("fetch");
+    let (base_url, server) = common::serve(vec![("200 OK", "10 13 16\n"), ("200 OK", PUZZLE_PAGE)]);
+
+    let output = new(&dir, &["9", "--fetch"], &base_url);
+    assert!(output.status.success(), "{output:?}");
+    let requests = server.join().unwrap();
+    assert_eq!(requests[0][0], "GET /2023/day/9/input HTTP/1.1");
+    assert_eq!(requests[1][0], "GET /2023/day/9 HTTP/1.1");
+    assert_eq!(
+        fs::read_to_string(dir.join("day09/input")).unwrap(),
+        "10 13 16\n"
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("day09/example1")).unwrap(),
+        "0 3 6 9 12 15\n1 3 6 10 15 21\n"
+    );
+    let answers = fs::read_to_string(dir.join("day09/answers.toml")).unwrap();
+    assert!(answers.ends_with("\n[example1]\npart1 = 46\n"));
+
+    fs::remove_dir_all(dir).unwrap();
+}
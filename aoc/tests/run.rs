@@ -0,0 +1,90 @@
+//! The `run` script at the workspace root, driven with a stand-in `cargo` that skips to the
+//! arguments after `--` and hands them to the already built runner
+#![cfg(unix)]
+
+use std::{
+    env, fs,
+    io::Write,
+    os::unix::fs::PermissionsExt,
+    path::PathBuf,
+    process::{Command, Output, Stdio},
+};
+
+mod common;
+
+const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+/// a scratch directory with day04/example2 and the stand-in cargo in bin/
+fn sandbox(name: &str) -> PathBuf {
+    let dir = common::workspace("run", name, &[], &["bin", "day04"]);
+    fs::write(dir.join("day04/example2"), EXAMPLE).unwrap();
+
+    let cargo = dir.join("bin/cargo");
+    fs::write(
+        &cargo,
+        format!(
+            "#!/bin/bash\n\
+             while [ $# -gt 0 ] && [ \"$1\" != -- ]; do shift; done\n\
+             shift\n\
+             exec '{}' \"$@\"\n",
+            env!("CARGO_BIN_EXE_aoc")
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&cargo, fs::Permissions::from_mode(0o755)).unwrap();
+    dir
+}
+
+/// runs the workspace's `run` script in `dir` with the stand-in cargo first on the path
+fn run(dir: &PathBuf, args: &[&str], stdin: &str) -> Output {
+    let script = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../run");
+    let path = format!(
+        "{}:{}",
+        dir.join("bin").display(),
+        env::var("PATH").unwrap()
+    );
+    let mut child = Command::new("bash")
+        .arg(script)
+        .args(args)
+        .current_dir(dir)
+        .env("PATH", path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn run_passes_the_arguments_to_the_binary() {
+    let dir = sandbox("args");
+
+    for args in [
+        &["aoc", "run", "4", "2", "--input", "-"][..],
+        &["aoc", "run", "4", "2", "--example", "2"],
+    ] {
+        let output = run(&dir, args, EXAMPLE);
+        assert!(output.status.success(), "{args:?}: {output:?}");
+        assert!(String::from_utf8_lossy(&output.stdout).contains("30"));
+    }
+
+    // flags cargo would understand itself reach the binary as well
+    let output = run(&dir, &["aoc", "run", "4", "--release"], "");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("'--release'"));
+
+    fs::remove_dir_all(dir).unwrap();
+}
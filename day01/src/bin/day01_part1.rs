@@ -1,19 +1,7 @@
-use anyhow::{Context, Result};
-use utils::get_input_file;
+use anyhow::Result;
+use day01::Day01;
+use utils::{report, Part};
 
 fn main() -> Result<()> {
-    let data = get_input_file()?;
-    let mut sum = 0;
-    data.for_each(|calbr| {
-        let nums = calbr
-            .context("Line is not a string")
-            .unwrap()
-            .chars()
-            .filter_map(|c| c.to_digit(10))
-            .collect::<Vec<_>>();
-        let val = (10 * nums.first().unwrap()) + nums.last().unwrap();
-        sum += val;
-    });
-    dbg!(sum);
-    Ok(())
+    report::run::<Day01>(Part::One)
 }
@@ -0,0 +1,266 @@
+use anyhow::{bail, Context, Result};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, VecDeque},
+};
+use utils::{Answer, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Ok(input.trim_end_matches(['\r', '\n']).lines().collect())
+    }
+
+    fn part1(data: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(calibration_sum(data, &Vocabulary::digits())?.into())
+    }
+
+    fn part2(data: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(calibration_sum(data, &Vocabulary::english())?.into())
+    }
+}
+
+/// Sum of the numbers made of the first and the last digit of every line
+pub fn calibration_sum(lines: &[&str], vocabulary: &Vocabulary) -> Result<u32> {
+    lines
+        .iter()
+        .map(|line| {
+            let (first, last) = vocabulary
+                .first_and_last(line)
+                .context(format!("No digit in line '{line}'"))?;
+            Ok(10 * first + last)
+        })
+        .sum()
+}
+
+/// The words that count as digits, on top of the digits themselves.
+///
+/// Lines are scanned with an Aho-Corasick automaton, which finds every word in one pass, also
+/// where words overlap like in `twone`.
+#[derive(Clone, Debug)]
+pub struct Vocabulary {
+    nodes: Vec<Node>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Node {
+    children: BTreeMap<u8, usize>,
+    /// the node of the longest proper suffix of this node's prefix that is in the trie
+    fail: usize,
+    /// length and digit of every word ending here, longest first
+    words: Vec<(usize, u32)>,
+}
+
+/// A digit found in a line
+#[derive(Clone, Copy)]
+struct Found {
+    start: usize,
+    len: usize,
+    digit: u32,
+}
+
+impl Vocabulary {
+    pub const ENGLISH: [&'static str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    /// `words` are paired with the digit they stand for, which has to be below 10
+    pub fn new<'a>(words: impl IntoIterator<Item = (&'a str, u32)>) -> Result<Self> {
+        let mut vocabulary = Vocabulary {
+            nodes: vec![Node::default()],
+        };
+        let digits = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+        for (word, digit) in digits.into_iter().zip(0..).chain(words) {
+            vocabulary.insert(word, digit)?;
+        }
+        vocabulary.link();
+        Ok(vocabulary)
+    }
+
+    /// only the digits themselves
+    pub fn digits() -> Self {
+        Self::new([]).expect("digits are valid words")
+    }
+
+    /// the digits and `one` to `nine`
+    pub fn english() -> Self {
+        Self::new(Self::ENGLISH.into_iter().zip(1..)).expect("english words are valid")
+    }
+
+    fn insert(&mut self, word: &str, digit: u32) -> Result<()> {
+        if word.is_empty() {
+            bail!("Empty words can't stand for digits");
+        }
+        if digit > 9 {
+            bail!("'{word}' stands for {digit}, which is no digit");
+        }
+        let mut node = 0;
+        for &byte in word.as_bytes() {
+            node = match self.nodes[node].children.get(&byte) {
+                Some(&child) => child,
+                None => {
+                    self.nodes.push(Node::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children.insert(byte, child);
+                    child
+                }
+            };
+        }
+        match self.nodes[node].words.first() {
+            Some(&(_, known)) if known != digit => {
+                bail!("'{word}' can't stand for both {known} and {digit}")
+            }
+            Some(_) => {}
+            None => self.nodes[node].words.push((word.len(), digit)),
+        }
+        Ok(())
+    }
+
+    /// sets the fail links breadth first, so that every node inherits the words of its fail node
+    fn link(&mut self) {
+        let mut queue: VecDeque<usize> = self.nodes[0].children.values().copied().collect();
+        while let Some(node) = queue.pop_front() {
+            let children: Vec<(u8, usize)> = self.nodes[node]
+                .children
+                .iter()
+                .map(|(&byte, &child)| (byte, child))
+                .collect();
+            for (byte, child) in children {
+                let fail = self.step(self.nodes[node].fail, byte);
+                let inherited = self.nodes[fail].words.clone();
+                self.nodes[child].fail = fail;
+                self.nodes[child].words.extend(inherited);
+                queue.push_back(child);
+            }
+        }
+    }
+
+    /// the node reached from `node` by reading `byte`
+    fn step(&self, mut node: usize, byte: u8) -> usize {
+        loop {
+            if let Some(&child) = self.nodes[node].children.get(&byte) {
+                return child;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = self.nodes[node].fail;
+        }
+    }
+
+    /// The digits of the words starting first and last in `line`. If several words start at
+    /// the same place the longest one counts.
+    pub fn first_and_last(&self, line: &str) -> Option<(u32, u32)> {
+        let mut first: Option<Found> = None;
+        let mut last: Option<Found> = None;
+        let mut node = 0;
+        for (end, &byte) in line.as_bytes().iter().enumerate() {
+            node = self.step(node, byte);
+            for &(len, digit) in &self.nodes[node].words {
+                let found = Found {
+                    start: end + 1 - len,
+                    len,
+                    digit,
+                };
+                // earlier and longer wins for the first, later and longer for the last word
+                if first
+                    .is_none_or(|f| (found.start, Reverse(found.len)) < (f.start, Reverse(f.len)))
+                {
+                    first = Some(found);
+                }
+                if last.is_none_or(|l| (found.start, found.len) > (l.start, l.len)) {
+                    last = Some(found);
+                }
+            }
+        }
+        Some((first?.digit, last?.digit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    const EXAMPLE1: &str = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+    const EXAMPLE2: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            Day01::solve(EXAMPLE1, Part::One).unwrap().to_string(),
+            "142"
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day01::solve(EXAMPLE2, Part::Two).unwrap().to_string(),
+            "281"
+        );
+    }
+
+    #[test]
+    fn line_without_digits_is_an_error() {
+        assert!(Day01::solve("abc", Part::One).is_err());
+    }
+
+    #[test]
+    fn overlapping_words_count_twice() {
+        let english = Vocabulary::english();
+        assert_eq!(english.first_and_last("eightwo"), Some((8, 2)));
+        assert_eq!(english.first_and_last("twone"), Some((2, 1)));
+        assert_eq!(english.first_and_last("oneight"), Some((1, 8)));
+        assert_eq!(english.first_and_last("sevenine"), Some((7, 9)));
+        assert_eq!(english.first_and_last("xtwonex"), Some((2, 1)));
+        assert_eq!(english.first_and_last("nin"), None);
+        assert_eq!(Vocabulary::digits().first_and_last("eightwo"), None);
+    }
+
+    #[test]
+    fn longer_words_win_at_the_same_start() {
+        let vocabulary = Vocabulary::new([("ab", 1), ("abcde", 2), ("bc", 3)]).unwrap();
+        assert_eq!(vocabulary.first_and_last("abcde"), Some((2, 3)));
+        assert_eq!(vocabulary.first_and_last("xbcdeab"), Some((3, 1)));
+    }
+
+    #[test]
+    fn other_languages() {
+        let german = Vocabulary::new([
+            ("eins", 1),
+            ("zwei", 2),
+            ("drei", 3),
+            ("vier", 4),
+            ("fünf", 5),
+            ("sechs", 6),
+            ("sieben", 7),
+            ("acht", 8),
+            ("neun", 9),
+        ])
+        .unwrap();
+        assert_eq!(german.first_and_last("xfünfzweinsx"), Some((5, 1)));
+        assert_eq!(
+            calibration_sum(&["neunacht", "sechs7"], &german).unwrap(),
+            98 + 67
+        );
+        assert!(Vocabulary::new([("zehn", 10)]).is_err());
+        assert!(Vocabulary::new([("one", 1), ("one", 2)]).is_err());
+        assert!(Vocabulary::new([("1", 1)]).is_ok());
+    }
+}
@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Parser;
+use day02::{Bag, Day02};
+use utils::{InputSource, Solution};
+
+#[derive(Parser)]
+#[command(about = "Checks every game of day 2 against a bag and shows which grabs are impossible")]
+struct Cli {
+    /// Puzzle input file or - for stdin, defaults to day02/input
+    input: Option<InputSource>,
+    /// Read day02/exampleN instead of the puzzle input, day02/example1 if N is left out
+    #[arg(
+        long,
+        value_name = "N",
+        num_args = 0..=1,
+        default_missing_value = "1",
+        conflicts_with = "input"
+    )]
+    example: Option<u8>,
+    /// Cubes in the bag like red=12,green=13,blue=14, the puzzle's bag if left out
+    #[arg(long, conflicts_with = "config")]
+    bag: Option<Bag>,
+    /// TOML file with one `color = count` line per color in the bag
+    #[arg(long)]
+    config: Option<PathBuf>,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let bag = match (cli.bag, cli.config) {
+        (Some(bag), _) => bag,
+        (None, Some(path)) => Bag::load(path)?,
+        (None, None) => Bag::puzzle(),
+    };
+    let input = cli
+        .example
+        .map(InputSource::Example)
+        .or(cli.input)
+        .unwrap_or_default()
+        .read(Day02::DAY)?;
+    let game_set = Day02::parse(&input)?;
+
+    let reports = game_set.check(&bag);
+    for report in &reports {
+        println!("{report}");
+    }
+    let possible: Vec<u64> = reports
+        .iter()
+        .filter(|report| report.possible())
+        .map(|report| report.nr)
+        .collect();
+    println!(
+        "{} of {} games are possible, their numbers add up to {}",
+        possible.len(),
+        reports.len(),
+        possible.iter().sum::<u64>()
+    );
+    Ok(())
+}
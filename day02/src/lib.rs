@@ -0,0 +1,337 @@
+use anyhow::{bail, Context, Result};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, space1},
+    combinator::map,
+    multi::separated_list1,
+    sequence::separated_pair,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt, fs,
+    path::Path,
+    str::FromStr,
+};
+use utils::{
+    get_u64,
+    parse::{complete, lines, numbered},
+    Answer, Solution,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed<'a> = GameSet<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let get_cube = map(separated_pair(get_u64, space1, alpha1), |(n, color)| {
+            (color, n)
+        });
+        let get_grab = map(separated_list1(tag(", "), get_cube), |cubes| {
+            cubes
+                .into_iter()
+                .fold(Cubes::new(), |mut grab, (color, n)| {
+                    *grab.entry(color).or_default() += n;
+                    grab
+                })
+        });
+        let get_game = map(
+            numbered("Game", separated_list1(tag("; "), get_grab)),
+            |(nr, grabs)| Game { nr, grabs },
+        );
+
+        let games = complete(input, lines(get_game))?;
+        Ok(GameSet { games })
+    }
+
+    fn part1(data: &Self::Parsed<'_>) -> Result<Answer> {
+        let possible: u64 = data
+            .check(&Bag::puzzle())
+            .iter()
+            .filter(|report| report.possible())
+            .map(|report| report.nr)
+            .sum();
+        Ok(possible.into())
+    }
+
+    fn part2(data: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(data.sum_of_powers()?.into())
+    }
+}
+
+/// Number of cubes per color
+pub type Cubes<'a> = BTreeMap<&'a str, u64>;
+
+#[derive(Debug)]
+pub struct GameSet<'a> {
+    pub games: Vec<Game<'a>>,
+}
+
+impl<'a> GameSet<'a> {
+    /// every color that shows up in any of the games
+    pub fn colors(&self) -> BTreeSet<&'a str> {
+        self.games
+            .iter()
+            .flat_map(|game| game.grabs.iter().flat_map(|grab| grab.keys().copied()))
+            .collect()
+    }
+
+    /// checks every game against the cubes in `bag`
+    pub fn check(&self, bag: &Bag) -> Vec<GameReport<'a>> {
+        self.games.iter().map(|game| game.check(bag)).collect()
+    }
+
+    fn sum_of_powers(&self) -> Result<u64> {
+        let colors = self.colors();
+        self.games.iter().map(|g| g.power(&colors)).sum()
+    }
+}
+
+#[derive(Debug)]
+pub struct Game<'a> {
+    pub nr: u64,
+    pub grabs: Vec<Cubes<'a>>,
+}
+
+impl<'a> Game<'a> {
+    pub fn check(&self, bag: &Bag) -> GameReport<'a> {
+        let violations = self
+            .grabs
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, grab)| {
+                grab.iter().filter_map(move |(&color, &count)| {
+                    let limit = bag.limit(color);
+                    (count > limit).then_some(Violation {
+                        grab: idx + 1,
+                        color,
+                        count,
+                        limit,
+                    })
+                })
+            })
+            .collect();
+        GameReport {
+            nr: self.nr,
+            violations,
+        }
+    }
+
+    /// Product of the fewest cubes of each of `colors` the game could have been played with.
+    /// Colors that never show up in the game need no cubes, which makes the power 0.
+    fn power(&self, colors: &BTreeSet<&str>) -> Result<u64> {
+        let fewest = self.fewest_cubes_possible()?;
+        Ok(colors
+            .iter()
+            .map(|color| fewest.get(color).copied().unwrap_or_default())
+            .product())
+    }
+
+    fn fewest_cubes_possible(&self) -> Result<Cubes<'a>> {
+        if self.grabs.is_empty() {
+            bail!("Game {} has no grabs", self.nr);
+        }
+        let mut fewest = Cubes::new();
+        for (&color, &count) in self.grabs.iter().flatten() {
+            let most = fewest.entry(color).or_default();
+            *most = (*most).max(count);
+        }
+        Ok(fewest)
+    }
+}
+
+/// How many cubes of each color are in the bag, colors that are not listed have no cubes
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bag(BTreeMap<String, u64>);
+
+impl Bag {
+    pub fn new<'a>(limits: impl IntoIterator<Item = (&'a str, u64)>) -> Self {
+        Bag(limits
+            .into_iter()
+            .map(|(color, count)| (color.to_owned(), count))
+            .collect())
+    }
+
+    /// the bag of the puzzle description
+    pub fn puzzle() -> Self {
+        Self::new([("red", 12), ("green", 13), ("blue", 14)])
+    }
+
+    /// reads a TOML file with one `color = count` line per color
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content =
+            fs::read_to_string(path).context(format!("Could not read bag {}", path.display()))?;
+        let limits =
+            toml::from_str(&content).context(format!("{} is no valid bag", path.display()))?;
+        Ok(Bag(limits))
+    }
+
+    pub fn limit(&self, color: &str) -> u64 {
+        self.0.get(color).copied().unwrap_or_default()
+    }
+}
+
+/// Parses a bag given as `red=12,green=13,blue=14`
+impl FromStr for Bag {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let limits = s
+            .split(',')
+            .map(|limit| {
+                let (color, count) = limit
+                    .split_once('=')
+                    .context(format!("'{limit}' is not of the form color=count"))?;
+                let count = count
+                    .trim()
+                    .parse()
+                    .context(format!("'{count}' is no number of cubes"))?;
+                Ok((color.trim(), count))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Bag::new(limits))
+    }
+}
+
+/// A grab that shows more cubes of a color than there are in the bag
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation<'a> {
+    /// the number of the grab in its game, counted from 1
+    pub grab: usize,
+    pub color: &'a str,
+    pub count: u64,
+    pub limit: u64,
+}
+
+impl Violation<'_> {
+    /// how many cubes are missing from the bag
+    pub fn excess(&self) -> u64 {
+        self.count - self.limit
+    }
+}
+
+/// Whether a game could have been played with a bag and which grabs make it impossible
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GameReport<'a> {
+    pub nr: u64,
+    pub violations: Vec<Violation<'a>>,
+}
+
+impl GameReport<'_> {
+    pub fn possible(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl fmt::Display for GameReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}: ", self.nr)?;
+        if self.possible() {
+            return write!(f, "possible");
+        }
+        for (idx, v) in self.violations.iter().enumerate() {
+            if idx > 0 {
+                write!(f, "; ")?;
+            }
+            write!(
+                f,
+                "grab {} shows {} {}, {} more than the bag holds",
+                v.grab,
+                v.count,
+                v.color,
+                v.excess()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day02::solve(EXAMPLE, Part::One).unwrap().to_string(), "8");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day02::solve(EXAMPLE, Part::Two).unwrap().to_string(),
+            "2286"
+        );
+    }
+
+    #[test]
+    fn power_of_fewest_cubes() {
+        let game_set = Day02::parse(EXAMPLE).unwrap();
+        let colors = game_set.colors();
+        let powers = game_set
+            .games
+            .iter()
+            .map(|g| g.power(&colors).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(powers, vec![48, 12, 1560, 630, 36]);
+    }
+
+    #[test]
+    fn reports_which_grabs_are_impossible() {
+        let game_set = Day02::parse(EXAMPLE).unwrap();
+        let reports = game_set.check(&Bag::puzzle());
+        assert_eq!(reports[0].to_string(), "Game 1: possible");
+        assert_eq!(
+            reports[2].violations,
+            [Violation {
+                grab: 1,
+                color: "red",
+                count: 20,
+                limit: 12
+            }]
+        );
+        assert_eq!(
+            reports[3].to_string(),
+            "Game 4: grab 3 shows 15 blue, 1 more than the bag holds; \
+             grab 3 shows 14 red, 2 more than the bag holds"
+        );
+
+        let small: Bag = "red=1, green=1".parse().unwrap();
+        let report = &game_set.check(&small)[1];
+        assert_eq!(report.violations.len(), 5);
+        // there are no blue cubes in the bag at all
+        assert_eq!(report.violations[0].color, "blue");
+        assert_eq!(report.violations[0].excess(), 1);
+        assert!("red:1".parse::<Bag>().is_err());
+    }
+
+    #[test]
+    fn any_colors_can_be_played() {
+        let game_set =
+            Day02::parse("Game 1: 2 cyan, 1 magenta; 3 cyan\nGame 2: 1 yellow, 4 cyan, 2 cyan")
+                .unwrap();
+        assert_eq!(
+            game_set.colors().into_iter().collect::<Vec<_>>(),
+            ["cyan", "magenta", "yellow"]
+        );
+        assert_eq!(game_set.games[1].grabs[0]["cyan"], 6);
+        let bag = Bag::new([("cyan", 5), ("magenta", 1), ("yellow", 1)]);
+        let possible: Vec<_> = game_set
+            .check(&bag)
+            .iter()
+            .map(|report| report.possible())
+            .collect();
+        assert_eq!(possible, [true, false]);
+        // game 1 needs no yellow and game 2 no magenta cubes
+        assert_eq!(game_set.sum_of_powers().unwrap(), 0);
+    }
+}
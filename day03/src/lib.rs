@@ -0,0 +1,291 @@
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::{ensure, Result};
+use utils::{
+    grid::{Grid, Pos},
+    Answer, Solution,
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed<'a> = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        Schematic::new(input)
+    }
+
+    fn part1(data: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(data
+            .part_numbers()
+            .map(|span| span.value)
+            .sum::<u64>()
+            .into())
+    }
+
+    fn part2(data: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(data.gear_ratios().sum::<u64>().into())
+    }
+}
+
+#[derive(Debug)]
+enum Position {
+    Number(u8),
+    Dot,
+    Symbol(char),
+}
+
+/// A number in the schematic covering `len` columns of `row` from `col` on
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub value: u64,
+    pub row: usize,
+    pub col: usize,
+    pub len: usize,
+}
+
+impl Span {
+    pub fn cells(&self) -> impl Iterator<Item = Pos> + '_ {
+        (self.col..self.col + self.len).map(|col| (self.row, col))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Symbol {
+    pub kind: char,
+    pub pos: Pos,
+}
+
+/// The numbers and symbols of a schematic and which of them are next to each other, also
+/// diagonally. Spans and symbols are numbered in reading order, the queries refer to them by
+/// these indices.
+#[derive(Debug)]
+pub struct Schematic {
+    spans: Vec<Span>,
+    symbols: Vec<Symbol>,
+    /// the symbols next to each span
+    span_symbols: Vec<Vec<usize>>,
+    /// the spans next to each symbol
+    symbol_spans: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    fn new(data: &str) -> Result<Schematic> {
+        let grid = Grid::parse(data, |c| {
+            Some(if let Some(digit) = c.to_digit(10) {
+                Position::Number(digit as u8)
+            } else if c == '.' {
+                Position::Dot
+            } else {
+                Position::Symbol(c)
+            })
+        })?;
+        ensure!(grid.rows() > 0, "Schematic is empty");
+
+        let mut spans = vec![];
+        let mut symbols = vec![];
+        for row in 0..grid.rows() {
+            let mut span: Option<Span> = None;
+            for col in 0..grid.cols() {
+                match grid[(row, col)] {
+                    Position::Number(digit) => {
+                        let span = span.get_or_insert(Span {
+                            value: 0,
+                            row,
+                            col,
+                            len: 0,
+                        });
+                        span.value = 10 * span.value + digit as u64;
+                        span.len += 1;
+                        continue;
+                    }
+                    Position::Symbol(kind) => symbols.push(Symbol {
+                        kind,
+                        pos: (row, col),
+                    }),
+                    Position::Dot => {}
+                }
+                spans.extend(span.take());
+            }
+            spans.extend(span);
+        }
+
+        let symbol_at: HashMap<Pos, usize> = symbols
+            .iter()
+            .enumerate()
+            .map(|(idx, symbol)| (symbol.pos, idx))
+            .collect();
+        let mut span_symbols = vec![vec![]; spans.len()];
+        let mut symbol_spans = vec![vec![]; symbols.len()];
+        for (span_idx, span) in spans.iter().enumerate() {
+            let mut adjacent: Vec<usize> = span
+                .cells()
+                .flat_map(|cell| grid.neighbours8(cell))
+                .filter_map(|pos| symbol_at.get(&pos).copied())
+                .collect();
+            adjacent.sort_unstable();
+            adjacent.dedup();
+            for &symbol_idx in &adjacent {
+                symbol_spans[symbol_idx].push(span_idx);
+            }
+            span_symbols[span_idx] = adjacent;
+        }
+
+        Ok(Schematic {
+            spans,
+            symbols,
+            span_symbols,
+            symbol_spans,
+        })
+    }
+
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// the numbers next to the symbol with index `symbol`
+    pub fn numbers_touching(&self, symbol: usize) -> impl Iterator<Item = &Span> {
+        self.symbol_spans[symbol]
+            .iter()
+            .map(|&idx| &self.spans[idx])
+    }
+
+    /// the symbols next to the span with index `span`
+    pub fn symbols_touching(&self, span: usize) -> impl Iterator<Item = &Symbol> {
+        self.span_symbols[span]
+            .iter()
+            .map(|&idx| &self.symbols[idx])
+    }
+
+    /// indices of the symbols that are next to exactly `n` numbers
+    pub fn symbols_with_neighbours(&self, n: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.symbols.len()).filter(move |&idx| self.symbol_spans[idx].len() == n)
+    }
+
+    /// numbers next to at least one symbol
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Span> {
+        self.spans
+            .iter()
+            .zip(&self.span_symbols)
+            .filter(|(_, symbols)| !symbols.is_empty())
+            .map(|(span, _)| span)
+    }
+
+    /// The part numbers next to each kind of symbol. A number next to several symbols of a
+    /// kind is listed once for each of them.
+    pub fn part_numbers_by_kind(&self) -> BTreeMap<char, Vec<u64>> {
+        let mut by_kind: BTreeMap<char, Vec<u64>> = BTreeMap::new();
+        for (symbol, spans) in self.symbols.iter().zip(&self.symbol_spans) {
+            by_kind
+                .entry(symbol.kind)
+                .or_default()
+                .extend(spans.iter().map(|&idx| self.spans[idx].value));
+        }
+        by_kind
+    }
+
+    /// products of the two numbers next to every `*` that touches exactly two
+    pub fn gear_ratios(&self) -> impl Iterator<Item = u64> + '_ {
+        self.symbols_with_neighbours(2)
+            .filter(|&idx| self.symbols[idx].kind == '*')
+            .map(|idx| self.numbers_touching(idx).map(|span| span.value).product())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    const EXAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            Day03::solve(EXAMPLE, Part::One).unwrap().to_string(),
+            "4361"
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day03::solve(EXAMPLE, Part::Two).unwrap().to_string(),
+            "467835"
+        );
+    }
+
+    #[test]
+    fn numbers_without_symbols_are_no_parts() {
+        let schematic = Schematic::new(EXAMPLE).unwrap();
+        let parts: Vec<u64> = schematic.part_numbers().map(|span| span.value).collect();
+        assert!(parts.contains(&467));
+        assert!(parts.contains(&617));
+        assert!(!parts.contains(&114));
+        assert!(!parts.contains(&58));
+        assert_eq!(schematic.spans().len(), 10);
+        assert_eq!(
+            schematic.spans()[2],
+            Span {
+                value: 35,
+                row: 2,
+                col: 2,
+                len: 2
+            }
+        );
+    }
+
+    #[test]
+    fn index_works_both_ways() {
+        let schematic = Schematic::new(EXAMPLE).unwrap();
+        // the * at (1, 3) is the first symbol
+        assert_eq!(
+            schematic.symbols()[0],
+            Symbol {
+                kind: '*',
+                pos: (1, 3)
+            }
+        );
+        let numbers: Vec<u64> = schematic.numbers_touching(0).map(|s| s.value).collect();
+        assert_eq!(numbers, [467, 35]);
+        let kinds: Vec<char> = schematic.symbols_touching(0).map(|s| s.kind).collect();
+        assert_eq!(kinds, ['*']);
+        assert_eq!(schematic.symbols_with_neighbours(1).count(), 4);
+
+        let by_kind = schematic.part_numbers_by_kind();
+        assert_eq!(by_kind[&'#'], [633]);
+        assert_eq!(by_kind[&'$'], [664]);
+        assert_eq!(by_kind[&'*'], [467, 35, 617, 755, 598]);
+    }
+
+    #[test]
+    fn gears_touch_exactly_two_numbers() {
+        // the first * touches three numbers, 10 sits between two gears
+        let schematic = "2.3..4..
+.*......
+.5..10*7
+....*...
+.....8..";
+        let gears: Vec<u64> = Schematic::new(schematic).unwrap().gear_ratios().collect();
+        assert_eq!(gears, [70, 80]);
+        assert_eq!(
+            Day03::solve(schematic, Part::Two).unwrap().to_string(),
+            "150"
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::Result;
+use clap::Parser;
+use day04::{Day04, Overflow};
+use utils::{InputSource, Solution};
+
+#[derive(Parser)]
+#[command(about = "Shows how many copies of every day 4 scratchcard are won and from which cards")]
+struct Cli {
+    /// Puzzle input file or - for stdin, defaults to day04/input
+    input: Option<InputSource>,
+    /// Read day04/exampleN instead of the puzzle input, day04/example1 if N is left out
+    #[arg(
+        long,
+        value_name = "N",
+        num_args = 0..=1,
+        default_missing_value = "1",
+        conflicts_with = "input"
+    )]
+    example: Option<u8>,
+    /// Only win the cards that are in the pile instead of failing when a card wins copies of
+    /// cards past the end
+    #[arg(long)]
+    clamp: bool,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let overflow = if cli.clamp {
+        Overflow::Clamp
+    } else {
+        Overflow::Fail
+    };
+    let input = cli
+        .example
+        .map(InputSource::Example)
+        .or(cli.input)
+        .unwrap_or_default()
+        .read(Day04::DAY)?;
+    let pile = Day04::parse(&input)?;
+
+    let mut total = 0;
+    for (id, origins) in pile.provenance(overflow)? {
+        let copies: u64 = origins.values().sum();
+        total += copies;
+        let from = origins
+            .iter()
+            .filter(|&(&origin, _)| origin != id)
+            .map(|(origin, count)| format!("{count} from card {origin}"))
+            .collect::<Vec<_>>();
+        if from.is_empty() {
+            println!("Card {id}: {copies}");
+        } else {
+            println!("Card {id}: {copies} ({})", from.join(", "));
+        }
+    }
+    println!("{total} scratchcards in total");
+    Ok(())
+}
@@ -0,0 +1,214 @@
+use std::collections::{BTreeMap, HashSet};
+
+use anyhow::{bail, Context, Result};
+use nom::{
+    character::complete::{char, space0},
+    combinator::map,
+    sequence::{separated_pair, tuple},
+};
+use utils::{
+    parse::{complete, lines, numbered, numbers},
+    Answer, Solution,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed<'a> = Pile;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let get_card = map(
+            numbered(
+                "Card",
+                separated_pair(numbers, tuple((space0, char('|'), space0)), numbers),
+            ),
+            |(id, (winners, draw))| Card { id, winners, draw },
+        );
+        Pile::new(complete(input, lines(get_card))?)
+    }
+
+    fn part1(pile: &Self::Parsed<'_>) -> Result<Answer> {
+        let total_value: u64 = pile.cards().iter().map(Card::points).sum();
+        Ok(total_value.into())
+    }
+
+    fn part2(pile: &Self::Parsed<'_>) -> Result<Answer> {
+        let copies = pile.cascade(Overflow::Fail)?;
+        Ok(copies.values().sum::<u64>().into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Card {
+    pub id: u64,
+    pub winners: Vec<u64>,
+    pub draw: Vec<u64>,
+}
+
+impl Card {
+    /// number of drawn numbers that are also winning numbers
+    pub fn matches(&self) -> usize {
+        let winners: HashSet<_> = self.winners.iter().collect();
+        let draw: HashSet<_> = self.draw.iter().collect();
+        winners.intersection(&draw).count()
+    }
+
+    /// 1 point for the first match, doubled for every further one
+    pub fn points(&self) -> u64 {
+        match self.matches() {
+            0 => 0,
+            n => 1 << (n - 1),
+        }
+    }
+}
+
+/// What to do when a card wins copies of cards that are not in the pile
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// only win the cards that are there
+    Clamp,
+    /// the pile is broken
+    Fail,
+}
+
+/// The scratchcards ordered by their IDs
+#[derive(Clone, Debug)]
+pub struct Pile {
+    cards: Vec<Card>,
+}
+
+impl Pile {
+    pub fn new(mut cards: Vec<Card>) -> Result<Self> {
+        cards.sort_by_key(|card| card.id);
+        if let Some(pair) = cards.windows(2).find(|pair| pair[0].id == pair[1].id) {
+            bail!("Card {} is in the pile twice", pair[0].id);
+        }
+        Ok(Pile { cards })
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Positions in the pile of the cards that `card` wins copies of. Card `n` with `m`
+    /// matches wins the cards `n + 1` to `n + m`.
+    fn won_by(&self, card: &Card, overflow: Overflow) -> Result<Vec<usize>> {
+        let mut won = vec![];
+        for offset in 1..=card.matches() as u64 {
+            let id = card.id + offset;
+            match self.cards.binary_search_by_key(&id, |card| card.id) {
+                Ok(idx) => won.push(idx),
+                Err(_) if overflow == Overflow::Clamp => {}
+                Err(_) => bail!(
+                    "Card {} wins a copy of card {id}, which is not in the pile",
+                    card.id
+                ),
+            }
+        }
+        Ok(won)
+    }
+
+    /// number of instances of every card, the original included, after all copies are won
+    pub fn cascade(&self, overflow: Overflow) -> Result<BTreeMap<u64, u64>> {
+        let mut copies = vec![1_u64; self.cards.len()];
+        // copies are only ever won of later cards, so every card is complete once reached
+        for (idx, card) in self.cards.iter().enumerate() {
+            for won in self.won_by(card, overflow)? {
+                copies[won] = copies[won]
+                    .checked_add(copies[idx])
+                    .context(format!("Too many copies of card {}", self.cards[won].id))?;
+            }
+        }
+        Ok(self.cards.iter().map(|card| card.id).zip(copies).collect())
+    }
+
+    /// Like `cascade`, but tells for every card how many of its instances go back to which
+    /// original card. The original of a card itself goes back to that card.
+    pub fn provenance(&self, overflow: Overflow) -> Result<BTreeMap<u64, BTreeMap<u64, u64>>> {
+        let mut origins: Vec<BTreeMap<u64, u64>> = self
+            .cards
+            .iter()
+            .map(|card| BTreeMap::from([(card.id, 1)]))
+            .collect();
+        for (idx, card) in self.cards.iter().enumerate() {
+            for won in self.won_by(card, overflow)? {
+                for (origin, count) in origins[idx].clone() {
+                    let total = origins[won].entry(origin).or_default();
+                    *total = total
+                        .checked_add(count)
+                        .context(format!("Too many copies of card {}", self.cards[won].id))?;
+                }
+            }
+        }
+        Ok(self.cards.iter().map(|card| card.id).zip(origins).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day04::solve(EXAMPLE, Part::One).unwrap().to_string(), "13");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day04::solve(EXAMPLE, Part::Two).unwrap().to_string(), "30");
+    }
+
+    #[test]
+    fn counts_matching_numbers() {
+        let pile = Day04::parse(EXAMPLE).unwrap();
+        let counts = pile.cards().iter().map(Card::matches).collect::<Vec<_>>();
+        assert_eq!(counts, vec![4, 2, 2, 1, 0, 0]);
+    }
+
+    #[test]
+    fn cascade_follows_card_ids() {
+        // card 4 is missing, so card 3 wins only card 5 and card 6 wins past the end
+        let pile = Day04::parse(
+            "Card 3: 1 2 | 1 2
+Card 5: 7 | 7
+Card 6: 8 9 | 9 8
+Card 7: 1 | 2",
+        )
+        .unwrap();
+        let copies = pile.cascade(Overflow::Clamp).unwrap();
+        assert_eq!(copies, BTreeMap::from([(3, 1), (5, 2), (6, 3), (7, 4)]));
+        let err = pile.cascade(Overflow::Fail).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Card 3 wins a copy of card 4, which is not in the pile"
+        );
+        assert!(Day04::solve("Card 1: 5 | 5", Part::Two).is_err());
+
+        assert!(Day04::parse("Card 1: 5 | 5\nCard 1: 6 | 6").is_err());
+    }
+
+    #[test]
+    fn provenance_adds_up_to_the_copies() {
+        let pile = Day04::parse(EXAMPLE).unwrap();
+        let copies = pile.cascade(Overflow::Fail).unwrap();
+        let provenance = pile.provenance(Overflow::Fail).unwrap();
+        for (id, origins) in &provenance {
+            assert_eq!(origins.values().sum::<u64>(), copies[id]);
+        }
+        // card 1 wins card 4 directly and through the copies of cards 2 and 3 it won
+        assert_eq!(
+            provenance[&4],
+            BTreeMap::from([(1, 4), (2, 2), (3, 1), (4, 1)])
+        );
+    }
+}
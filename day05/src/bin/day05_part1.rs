@@ -0,0 +1,7 @@
+use anyhow::Result;
+use day05::Day05;
+use utils::{report, Part};
+
+fn main() -> Result<()> {
+    report::run::<Day05>(Part::One)
+}
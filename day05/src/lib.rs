@@ -0,0 +1,212 @@
+use anyhow::{bail, ensure, Context, Result};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, line_ending},
+    sequence::{pair, separated_pair, terminated},
+};
+use utils::{
+    interval::{Interval, IntervalSet, RangeMap},
+    parse::{blocks, complete, header, labelled_block, numbers},
+    Answer, Solution,
+};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed<'a> = Almanac<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let label = terminated(separated_pair(alpha1, tag("-to-"), alpha1), tag(" map"));
+        let (seeds, blocks) = complete(
+            input,
+            separated_pair(
+                header("seeds", numbers),
+                pair(line_ending, line_ending),
+                blocks(labelled_block(label, numbers)),
+            ),
+        )?;
+
+        let mut layers = vec![];
+        for ((from, to), lines) in blocks {
+            let map = layer_map(&lines).context(format!("Bad {from}-to-{to} map"))?;
+            layers.push(Layer { from, to, map });
+        }
+        Almanac::new(seeds, layers)
+    }
+
+    fn part1(almanac: &Self::Parsed<'_>) -> Result<Answer> {
+        almanac
+            .seeds
+            .iter()
+            .map(|&seed| almanac.location(seed))
+            .min()
+            .map(Answer::from)
+            .context("There are no seeds")
+    }
+
+    fn part2(almanac: &Self::Parsed<'_>) -> Result<Answer> {
+        let locations = almanac.locations(&almanac.seed_ranges()?);
+        locations
+            .min()
+            .map(Answer::from)
+            .context("There are no seeds")
+    }
+}
+
+/// builds the map of a block whose lines are `destination source length`
+fn layer_map(lines: &[Vec<i64>]) -> Result<RangeMap> {
+    let rules = lines
+        .iter()
+        .map(|line| match line[..] {
+            [dest, source, len] if len >= 0 => Ok((Interval::with_len(source, len), dest - source)),
+            _ => bail!("{line:?} is not of the form destination source length"),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    RangeMap::new(rules)
+}
+
+/// One map of the almanac, moving numbers of category `from` to category `to`
+#[derive(Debug)]
+pub struct Layer<'a> {
+    pub from: &'a str,
+    pub to: &'a str,
+    pub map: RangeMap,
+}
+
+#[derive(Debug)]
+pub struct Almanac<'a> {
+    pub seeds: Vec<i64>,
+    layers: Vec<Layer<'a>>,
+}
+
+impl<'a> Almanac<'a> {
+    /// The layers have to lead from seeds to locations, each starting where the one before ends.
+    pub fn new(seeds: Vec<i64>, layers: Vec<Layer<'a>>) -> Result<Self> {
+        let mut category = "seed";
+        for layer in &layers {
+            ensure!(
+                layer.from == category,
+                "The {}-to-{} map follows a map to {category}",
+                layer.from,
+                layer.to
+            );
+            category = layer.to;
+        }
+        ensure!(
+            category == "location",
+            "The maps lead to {category} instead of location"
+        );
+        Ok(Almanac { seeds, layers })
+    }
+
+    pub fn layers(&self) -> &[Layer<'a>] {
+        &self.layers
+    }
+
+    /// the location of a single seed
+    pub fn location(&self, seed: i64) -> i64 {
+        self.layers.iter().fold(seed, |x, layer| layer.map.map(x))
+    }
+
+    /// all locations of `seeds`, moving whole intervals through the layers at once
+    pub fn locations(&self, seeds: &IntervalSet) -> IntervalSet {
+        self.layers
+            .iter()
+            .fold(seeds.clone(), |set, layer| layer.map.map_set(&set))
+    }
+
+    /// the seeds read as pairs of start and length
+    pub fn seed_ranges(&self) -> Result<IntervalSet> {
+        ensure!(
+            self.seeds.len().is_multiple_of(2),
+            "{} seed numbers don't make pairs of start and length",
+            self.seeds.len()
+        );
+        Ok(self
+            .seeds
+            .chunks(2)
+            .map(|pair| Interval::with_len(pair[0], pair[1]))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    const EXAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day05::solve(EXAMPLE, Part::One).unwrap().to_string(), "35");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day05::solve(EXAMPLE, Part::Two).unwrap().to_string(), "46");
+    }
+
+    #[test]
+    fn ranges_agree_with_single_seeds() {
+        let almanac = Day05::parse(EXAMPLE).unwrap();
+        let locations: Vec<i64> = [79, 14, 55, 13]
+            .into_iter()
+            .map(|seed| almanac.location(seed))
+            .collect();
+        assert_eq!(locations, [82, 43, 86, 35]);
+
+        let seeds = almanac.seed_ranges().unwrap();
+        let ranged = almanac.locations(&seeds);
+        assert_eq!(ranged.len(), seeds.len());
+        for seed in seeds.iter().flat_map(|i| i.start..i.end) {
+            assert!(ranged.contains(almanac.location(seed)));
+        }
+    }
+
+    #[test]
+    fn maps_have_to_chain_from_seed_to_location() {
+        let skipped = EXAMPLE.replace("soil-to-fertilizer", "soil-to-water");
+        assert!(Day05::parse(&skipped).is_err());
+        let short = EXAMPLE.split("\n\nhumidity").next().unwrap();
+        assert!(Day05::parse(short).is_err());
+        let overlapping = EXAMPLE.replace("52 50 48", "52 50 49");
+        assert!(Day05::parse(&overlapping).is_err());
+        let odd = EXAMPLE.replace("seeds: 79 14 55 13", "seeds: 79 14 55");
+        assert!(Day05::solve(&odd, Part::Two).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+use anyhow::{Context, Result};
+use nom::{character::complete::line_ending, sequence::separated_pair};
+use utils::{
+    parse::{complete, header, numbers},
+    Answer, Solution,
+};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed<'a> = Races;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let (times, distances) = complete(
+            input,
+            separated_pair(
+                header("Time", numbers),
+                line_ending,
+                header("Distance", numbers),
+            ),
+        )?;
+        Ok(Races { times, distances })
+    }
+
+    fn part1(races: &Self::Parsed<'_>) -> Result<Answer> {
+        let res: u64 = races
+            .times
+            .iter()
+            .zip(&races.distances)
+            .map(|(&time, &distance)| count_winning_holds(time, distance))
+            .product();
+        Ok(res.into())
+    }
+
+    fn part2(races: &Self::Parsed<'_>) -> Result<Answer> {
+        let (time, distance) = races.single_race()?;
+        Ok(count_winning_holds(time, distance).into())
+    }
+}
+
+/// Number of ways to hold the button of a race lasting `time` that go further than `distance`.
+///
+/// Holding for `h` goes `h * (time - h)`, so the winning holds lie strictly between the roots
+/// of `h² - time * h + distance`. Matching the distance exactly is no win.
+pub fn count_winning_holds(time: u64, distance: u64) -> u64 {
+    let (t, d) = (time as u128, distance as u128);
+    let wins = |hold: u128| hold * (t - hold) > d;
+    // the farthest one gets is by holding for half of the time
+    if !wins(t / 2) {
+        return 0;
+    }
+    // t² - 4d is positive as the middle wins, its square root puts us next to the lower root
+    let mut first = (t - (t * t - 4 * d).isqrt()) / 2;
+    while !wins(first) {
+        first += 1;
+    }
+    while first > 0 && wins(first - 1) {
+        first -= 1;
+    }
+    // the winning holds are symmetric around the middle: first..=time - first
+    (t - 2 * first + 1) as u64
+}
+
+#[derive(Debug)]
+pub struct Races {
+    times: Vec<u64>,
+    distances: Vec<u64>,
+}
+
+impl Races {
+    /// reread the input with bad kerning: all numbers on a line form one single number
+    fn single_race(&self) -> Result<(u64, u64)> {
+        Ok((concat_digits(&self.times)?, concat_digits(&self.distances)?))
+    }
+}
+
+/// the number written by putting `numbers` next to each other
+fn concat_digits(numbers: &[u64]) -> Result<u64> {
+    numbers
+        .iter()
+        .try_fold(0_u64, |acc, &n| {
+            let shift = 10_u64.checked_pow(n.checked_ilog10().unwrap_or_default() + 1)?;
+            acc.checked_mul(shift)?.checked_add(n)
+        })
+        .context("The race is too long")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    const EXAMPLE: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day06::solve(EXAMPLE, Part::One).unwrap().to_string(), "288");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day06::solve(EXAMPLE, Part::Two).unwrap().to_string(),
+            "71503"
+        );
+    }
+
+    #[test]
+    fn counts_winning_holds() {
+        assert_eq!(count_winning_holds(7, 9), 4);
+        assert_eq!(count_winning_holds(15, 40), 8);
+        // holding for 10 or 20 only matches the record
+        assert_eq!(count_winning_holds(30, 200), 9);
+        assert_eq!(count_winning_holds(4, 4), 0);
+        assert_eq!(count_winning_holds(4, 3), 1);
+        assert_eq!(count_winning_holds(5, 0), 4);
+        assert_eq!(count_winning_holds(0, 0), 0);
+        assert_eq!(count_winning_holds(1, 0), 0);
+        assert_eq!(count_winning_holds(10, 100), 0);
+        assert_eq!(count_winning_holds(u64::MAX, 0), u64::MAX - 1);
+        assert_eq!(count_winning_holds(u64::MAX, u64::MAX), u64::MAX - 3);
+    }
+
+    #[test]
+    fn closed_form_agrees_with_trying_every_hold() {
+        for time in 0..60 {
+            for distance in 0..=time * time / 4 + 1 {
+                let tried = (0..=time)
+                    .filter(|hold| hold * (time - hold) > distance)
+                    .count() as u64;
+                assert_eq!(
+                    count_winning_holds(time, distance),
+                    tried,
+                    "{time} {distance}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn single_race_ignores_spaces() {
+        let races = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(races.single_race().unwrap(), (71530, 940200));
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+
+/// Which card beats which and which one, if any, stands in for whatever makes the best hand
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+    /// the deck from the weakest to the strongest card
+    order: Vec<char>,
+    /// the wild card, it is weaker than any other card when comparing hands of the same type
+    wild: Option<char>,
+}
+
+impl Rules {
+    pub const DECK: &'static str = "23456789TJQKA";
+
+    /// `order` lists the cards from the weakest to the strongest
+    pub fn new(order: &str, wild: Option<char>) -> Result<Self> {
+        let order: Vec<char> = order.chars().collect();
+        if let Some(card) = order
+            .iter()
+            .enumerate()
+            .find_map(|(idx, card)| order[..idx].contains(card).then_some(card))
+        {
+            bail!("Card '{card}' appears twice in the deck");
+        }
+        if let Some(card) = wild.filter(|card| !order.contains(card)) {
+            bail!("The wild card '{card}' is not in the deck");
+        }
+        Ok(Rules { order, wild })
+    }
+
+    /// the rules of part 1, no wild card
+    pub fn standard() -> Self {
+        Self::new(Self::DECK, None).expect("the standard deck is valid")
+    }
+
+    /// the rules of part 2, jacks are jokers
+    pub fn jokers() -> Self {
+        Self::standard()
+            .with_wild('J')
+            .expect("jacks are in the deck")
+    }
+
+    /// the same card order with `card` being wild
+    pub fn with_wild(self, card: char) -> Result<Self> {
+        Self::new(&self.order.iter().collect::<String>(), Some(card))
+    }
+
+    /// strength of a card when breaking ties, the wild card is the weakest
+    fn strength(&self, card: char) -> Result<usize> {
+        if Some(card) == self.wild {
+            return Ok(0);
+        }
+        let idx = self.order.iter().position(|&c| c == card).context(format!(
+            "Card '{card}' is not in the deck {}",
+            self.order.iter().collect::<String>()
+        ))?;
+        Ok(idx + 1)
+    }
+
+    /// reads a hand like `KTJJT`
+    pub fn hand(&self, cards: &str) -> Result<Hand> {
+        let strengths: Vec<usize> = cards
+            .chars()
+            .map(|card| self.strength(card))
+            .collect::<Result<_>>()?;
+        let strengths: [usize; 5] = strengths
+            .try_into()
+            .ok()
+            .context(format!("Hand '{cards}' does not consist of 5 cards"))?;
+
+        let mut histogram = HashMap::new();
+        let mut wild = 0;
+        for card in cards.chars() {
+            if Some(card) == self.wild {
+                wild += 1;
+            } else {
+                *histogram.entry(card).or_insert(0) += 1;
+            }
+        }
+        let mut counts: Vec<usize> = histogram.into_values().collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(Hand {
+            h_type: HandType::from_counts(&counts, wild),
+            strengths,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    /// The type of a hand with `counts` cards of each kind, largest first, and `wild` wild
+    /// cards. Wild cards always do best by joining the largest group.
+    pub fn from_counts(counts: &[usize], wild: usize) -> Self {
+        let largest = counts.first().copied().unwrap_or_default() + wild;
+        let second = counts.get(1).copied().unwrap_or_default();
+        match (largest, second) {
+            (5.., _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+/// A hand ordered by its type first and then card by card
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hand {
+    pub h_type: HandType,
+    strengths: [usize; 5],
+}
+
+/// Sum of all bids, each multiplied by the rank of its hand
+pub fn total_winnings(game: &[(&str, u64)], rules: &Rules) -> Result<u64> {
+    let mut all_hands = game
+        .iter()
+        .map(|&(cards, bid)| Ok((rules.hand(cards)?, bid)))
+        .collect::<Result<Vec<_>>>()?;
+    all_hands.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    Ok(all_hands
+        .iter()
+        .enumerate()
+        .map(|(i, (_, bid))| ((i + 1) as u64) * bid)
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::EXAMPLE, Day07};
+    use utils::Solution;
+    use HandType::*;
+
+    fn types(rules: &Rules) -> Vec<HandType> {
+        Day07::parse(EXAMPLE)
+            .unwrap()
+            .into_iter()
+            .map(|(cards, _)| rules.hand(cards).unwrap().h_type)
+            .collect()
+    }
+
+    #[test]
+    fn classifies_example_hands() {
+        assert_eq!(
+            types(&Rules::standard()),
+            [OnePair, ThreeOfAKind, TwoPair, TwoPair, ThreeOfAKind]
+        );
+        assert_eq!(
+            types(&Rules::jokers()),
+            [OnePair, FourOfAKind, TwoPair, FourOfAKind, FourOfAKind]
+        );
+    }
+
+    #[test]
+    fn wild_cards_join_the_largest_group() {
+        let rules = Rules::jokers();
+        assert_eq!(rules.hand("JJJJJ").unwrap().h_type, FiveOfAKind);
+        assert_eq!(rules.hand("2JJJJ").unwrap().h_type, FiveOfAKind);
+        assert_eq!(rules.hand("22J33").unwrap().h_type, FullHouse);
+        assert_eq!(rules.hand("2345J").unwrap().h_type, OnePair);
+        assert_eq!(rules.hand("23JJ5").unwrap().h_type, ThreeOfAKind);
+        // the joker is the weakest card on ties, the jack is not
+        assert!(rules.hand("JKKK2").unwrap() < rules.hand("2QQQQ").unwrap());
+        let standard = Rules::standard();
+        assert!(standard.hand("JKKK3").unwrap() > standard.hand("2KKK3").unwrap());
+    }
+
+    #[test]
+    fn other_cards_can_be_wild() {
+        let game = Day07::parse(EXAMPLE).unwrap();
+        let queens = Rules::standard().with_wild('Q').unwrap();
+        assert_eq!(
+            types(&queens),
+            [OnePair, ThreeOfAKind, TwoPair, TwoPair, FourOfAKind]
+        );
+        // QQQJA was the strongest hand already
+        assert_eq!(total_winnings(&game, &queens).unwrap(), 6440);
+
+        let kings = Rules::standard().with_wild('K').unwrap();
+        assert_eq!(
+            types(&kings),
+            [
+                ThreeOfAKind,
+                ThreeOfAKind,
+                FourOfAKind,
+                FullHouse,
+                ThreeOfAKind
+            ]
+        );
+        assert_eq!(total_winnings(&game, &kings).unwrap(), 4602);
+    }
+
+    #[test]
+    fn rejects_bad_decks_and_cards() {
+        assert!(Rules::new("23A3", None).is_err());
+        assert!(Rules::new("23A", Some('J')).is_err());
+        assert!(Rules::standard().hand("2345X").is_err());
+        assert!(Rules::standard().hand("234567").is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use anyhow::Result;
+use nom::{
+    character::complete::{alphanumeric1, space1},
+    sequence::separated_pair,
+};
+use utils::{
+    get_u64,
+    parse::{complete, lines},
+    Answer, Solution,
+};
+
+mod cards;
+
+pub use cards::{total_winnings, Hand, HandType, Rules};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    /// hands as they are written in the input together with their bid
+    type Parsed<'a> = Vec<(&'a str, u64)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        complete(input, lines(separated_pair(alphanumeric1, space1, get_u64)))
+    }
+
+    fn part1(game: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(total_winnings(game, &Rules::standard())?.into())
+    }
+
+    fn part2(game: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(total_winnings(game, &Rules::jokers())?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    pub(crate) const EXAMPLE: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            Day07::solve(EXAMPLE, Part::One).unwrap().to_string(),
+            "6440"
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day07::solve(EXAMPLE, Part::Two).unwrap().to_string(),
+            "5905"
+        );
+    }
+
+    #[test]
+    fn hands_need_five_cards() {
+        assert!(Day07::solve("32T3 765", Part::One).is_err());
+    }
+}
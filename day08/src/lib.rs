@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+use nom::{
+    character::complete::{line_ending, one_of},
+    combinator::map,
+    multi::many1,
+    sequence::separated_pair,
+};
+use utils::{
+    cycle::{self, Cycle},
+    parse::{complete, lines, mapping},
+    Answer, Solution,
+};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed<'a> = (Vec<Instruction>, CamelMap<'a>);
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        let get_instr = map(many1(one_of("LR")), |v| {
+            v.iter()
+                .map(|instr| instr.into())
+                .collect::<Vec<Instruction>>()
+        });
+        let get_node = map(mapping, |(node, (left, right))| {
+            (node, Destination { left, right })
+        });
+
+        let (instrs, nodes) = complete(
+            input,
+            separated_pair(get_instr, many1(line_ending), lines(get_node)),
+        )?;
+        Ok((instrs, CamelMap::new(nodes)?))
+    }
+
+    fn part1((instrs, camel_map): &Self::Parsed<'_>) -> Result<Answer> {
+        camel_map.get("AAA")?;
+        let cycle = cycle::find_with_instructions(
+            "AAA",
+            instrs,
+            |node, instr| camel_map.step(node, instr),
+            |&node| node == "ZZZ",
+        );
+        let steps = cycle.first_hit().context("ZZZ can't be reached from AAA")?;
+        Ok(steps.into())
+    }
+
+    fn part2((instrs, camel_map): &Self::Parsed<'_>) -> Result<Answer> {
+        // every ghost ends up walking in circles, passing ..Z nodes at fixed points of its circle
+        let ghosts: Vec<Cycle> = camel_map
+            .0
+            .keys()
+            .filter(|node| node.ends_with('A'))
+            .map(|&start| {
+                cycle::find_with_instructions(
+                    start,
+                    instrs,
+                    |node, instr| camel_map.step(node, instr),
+                    |node| node.ends_with('Z'),
+                )
+            })
+            .collect();
+        if ghosts.is_empty() {
+            bail!("There are no ..A nodes to start from");
+        }
+        let steps = cycle::first_common_hit(&ghosts)
+            .context("The ghosts are never on ..Z nodes at the same time")?;
+        Ok(steps.into())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Left,
+    Right,
+}
+
+impl From<&char> for Instruction {
+    fn from(value: &char) -> Self {
+        match value {
+            'L' => Instruction::Left,
+            _ => Instruction::Right,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Destination<'a> {
+    left: &'a str,
+    right: &'a str,
+}
+
+#[derive(Debug)]
+pub struct CamelMap<'a>(HashMap<&'a str, Destination<'a>>);
+
+impl<'a> CamelMap<'a> {
+    /// builds the map, every destination has to be a node of the map as well
+    fn new(nodes: Vec<(&'a str, Destination<'a>)>) -> Result<Self> {
+        let camel_map = CamelMap(nodes.into_iter().collect());
+        for dests in camel_map.0.values() {
+            camel_map.get(dests.left)?;
+            camel_map.get(dests.right)?;
+        }
+        Ok(camel_map)
+    }
+
+    fn get(&self, key: &str) -> Result<&Destination<'a>> {
+        self.0.get(key).context(format!("Key '{key}' not found"))
+    }
+
+    /// the node reached from `node` by following `instr`
+    fn step(&self, node: &str, instr: &Instruction) -> &'a str {
+        // `new` made sure there are no dead ends
+        let dests = &self.0[node];
+        match instr {
+            Instruction::Left => dests.left,
+            Instruction::Right => dests.right,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    const EXAMPLE1: &str = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+    const EXAMPLE2: &str = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+    const EXAMPLE3: &str = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(Day08::solve(EXAMPLE1, Part::One).unwrap().to_string(), "2");
+        assert_eq!(Day08::solve(EXAMPLE2, Part::One).unwrap().to_string(), "6");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day08::solve(EXAMPLE3, Part::Two).unwrap().to_string(), "6");
+    }
+
+    #[test]
+    fn part2_with_offsets_that_differ_from_the_loop_length() {
+        // the first ghost is on 11Z after 2, 5, 8, … steps, the second one after 1, 3, 5, …
+        let input = "L
+
+11A = (11B, XXX)
+11B = (11Z, XXX)
+11Z = (11C, XXX)
+11C = (11B, XXX)
+22A = (22Z, XXX)
+22Z = (22B, XXX)
+22B = (22Z, XXX)
+XXX = (XXX, XXX)";
+        assert_eq!(Day08::solve(input, Part::Two).unwrap().to_string(), "5");
+    }
+
+    #[test]
+    fn dead_ends_are_rejected() {
+        assert!(Day08::parse("L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)").is_err());
+    }
+
+    #[test]
+    fn unreachable_zzz_is_an_error() {
+        let input = "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)";
+        let err = Day08::solve(input, Part::One).unwrap_err();
+        assert_eq!(err.to_string(), "ZZZ can't be reached from AAA");
+    }
+}
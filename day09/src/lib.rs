@@ -0,0 +1,161 @@
+use anyhow::{bail, Context, Result};
+use utils::{
+    parse::{complete, lines, numbers},
+    Answer, Solution,
+};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Parsed<'a> = Vec<Sequence>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        complete(input, lines(numbers::<i64>))?
+            .iter()
+            .enumerate()
+            .map(|(idx, values)| {
+                Sequence::new(values).context(format!("Sequence {} is no polynomial", idx + 1))
+            })
+            .collect()
+    }
+
+    fn part1(series: &Self::Parsed<'_>) -> Result<Answer> {
+        let res = series.iter().map(|seq| seq.next(1)).sum::<Result<i128>>()?;
+        Ok(res.into())
+    }
+
+    fn part2(series: &Self::Parsed<'_>) -> Result<Answer> {
+        let res = series.iter().map(|seq| seq.prev(1)).sum::<Result<i128>>()?;
+        Ok(res.into())
+    }
+}
+
+/// The polynomial behind a sequence of values, given as its Newton form: the first value, the
+/// first of its differences, the first of the differences of those and so on until they are
+/// all zero.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sequence {
+    leading: Vec<i128>,
+    len: usize,
+}
+
+impl Sequence {
+    /// Fails if the differences never become all zero, as then the values don't determine the
+    /// polynomial
+    pub fn new(values: &[i64]) -> Result<Self> {
+        let mut row: Vec<i128> = values.iter().map(|&v| v.into()).collect();
+        let mut leading = vec![];
+        while row.iter().any(|&v| v != 0) {
+            if row.len() < 2 {
+                bail!(
+                    "The differences of {} values never reach zero",
+                    values.len()
+                );
+            }
+            leading.push(row[0]);
+            row = row
+                .windows(2)
+                .map(|w| w[1].checked_sub(w[0]).context("Differences overflow"))
+                .collect::<Result<_>>()?;
+        }
+        Ok(Sequence {
+            leading,
+            len: values.len(),
+        })
+    }
+
+    /// degree of the polynomial, 0 for constants including the all zero sequence
+    pub fn degree(&self) -> usize {
+        self.leading.len().saturating_sub(1)
+    }
+
+    /// The value at position `x`, where 0 is the first value. Positions may lie before or after
+    /// the known values.
+    pub fn at(&self, x: i128) -> Result<i128> {
+        // the sum of leading[k] * binomial(x, k), where
+        // binomial(x, k + 1) = binomial(x, k) * (x - k) / (k + 1) divides exactly, also for x < 0
+        let mut binomial: i128 = 1;
+        let mut value: i128 = 0;
+        for (k, &lead) in self.leading.iter().enumerate() {
+            if k > 0 {
+                let k = k as i128;
+                binomial = binomial
+                    .checked_mul(x - (k - 1))
+                    .context("Extrapolation overflows")?
+                    / k;
+            }
+            value = lead
+                .checked_mul(binomial)
+                .and_then(|term| value.checked_add(term))
+                .context("Extrapolation overflows")?;
+        }
+        Ok(value)
+    }
+
+    /// the value `n` steps after the last one, `next(1)` is the one right after it
+    pub fn next(&self, n: u64) -> Result<i128> {
+        self.at(self.len as i128 - 1 + n as i128)
+    }
+
+    /// the value `n` steps before the first one, `prev(1)` is the one right before it
+    pub fn prev(&self, n: u64) -> Result<i128> {
+        self.at(-(n as i128))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    const EXAMPLE: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day09::solve(EXAMPLE, Part::One).unwrap().to_string(), "114");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day09::solve(EXAMPLE, Part::Two).unwrap().to_string(), "2");
+    }
+
+    #[test]
+    fn extrapolates_in_both_directions() {
+        let seq = Sequence::new(&[10, 13, 16, 21, 30, 45]).unwrap();
+        assert_eq!(seq.degree(), 3);
+        assert_eq!(seq.next(1).unwrap(), 68);
+        assert_eq!(seq.prev(1).unwrap(), 5);
+        assert_eq!(seq.at(2).unwrap(), 16);
+    }
+
+    #[test]
+    fn extrapolates_far_away() {
+        // n² + 1
+        let seq = Sequence::new(&[1, 2, 5, 10]).unwrap();
+        assert_eq!(seq.next(1).unwrap(), 17);
+        assert_eq!(seq.next(997).unwrap(), 1_000_001);
+        assert_eq!(seq.prev(3).unwrap(), 10);
+        assert_eq!(seq.prev(1_000_000_000).unwrap(), 1_000_000_000_000_000_001);
+        // (2⁶⁴)² does not fit into an i128
+        assert!(seq.next(u64::MAX).is_err());
+
+        let constant = Sequence::new(&[7, 7]).unwrap();
+        assert_eq!(constant.degree(), 0);
+        assert_eq!(constant.prev(5).unwrap(), 7);
+        assert_eq!(Sequence::new(&[0]).unwrap().next(3).unwrap(), 0);
+    }
+
+    #[test]
+    fn differences_have_to_reach_zero() {
+        assert!(Sequence::new(&[1, 2, 4, 8]).is_err());
+        assert!(Sequence::new(&[3]).is_err());
+        assert!(Sequence::new(&[]).is_ok());
+        let err = Day09::parse("1 2 3\n1 2 4").unwrap_err();
+        assert_eq!(err.to_string(), "Sequence 2 is no polynomial");
+    }
+}
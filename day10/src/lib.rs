@@ -0,0 +1,346 @@
+use anyhow::{ensure, Context, Result};
+use utils::{
+    geom::{Direction, Point},
+    grid::Grid,
+    search, Answer, Solution,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed<'a> = FieldMap;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        FieldMap::new(input.trim().parse()?)
+    }
+
+    fn part1(field_map: &Self::Parsed<'_>) -> Result<Answer> {
+        // a third pipe into the start would drag a branch that is no part of the loop along
+        field_map.get_start_adjacents()?;
+        // the tile farthest away along the loop is the one farthest away from the start
+        let distances = search::bfs(field_map.start_coord, |&pos| field_map.connected(pos));
+        let (_, steps) = distances.farthest().context("Nothing has been explored")?;
+        Ok(steps.into())
+    }
+
+    fn part2(field_map: &Self::Parsed<'_>) -> Result<Answer> {
+        let mut clean_map: Grid<Option<MapTile>> =
+            Grid::filled(field_map.grid.rows(), field_map.grid.cols(), None);
+
+        // build a clean map in which we can later on count the vertical paths
+        for point in field_map.main_loop()? {
+            let tile = match field_map.get(point) {
+                Some(MapTile::Start) => field_map.get_start_maptile()?,
+                tile => tile.cloned().context("The loop leaves the map")?,
+            };
+            *clean_map.at_mut(point).context("The loop leaves the map")? = Some(tile);
+        }
+
+        let mut area: usize = 0;
+        let mut inside = false;
+        let (rows, cols) = (clean_map.rows(), clean_map.cols());
+
+        // get ready for diagonal scanning!
+        for sum in 0..(rows + cols - 1) {
+            let mut row = sum;
+            if row >= rows {
+                row = rows - 1;
+            }
+
+            let mut col = sum - row;
+            if col >= cols {
+                col = cols - 1;
+            }
+
+            while row > 0 && col < cols - 1 {
+                if let Some(tile) = &clean_map[(row, col)] {
+                    inside = update_inside(tile, inside);
+                } else if inside {
+                    area += 1;
+                }
+                row -= 1;
+                col += 1;
+            }
+            if let Some(tile) = &clean_map[(row, col)] {
+                inside = update_inside(tile, inside);
+            } else if inside {
+                area += 1;
+            };
+        }
+
+        Ok(area.into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum MapTile {
+    NorthEast,
+    NorthWest,
+    NorthSouth,
+    EastWest,
+    SouthEast,
+    SouthWest,
+    Ground,
+    Start,
+}
+
+impl MapTile {
+    const PIPES: [MapTile; 6] = [
+        MapTile::NorthEast,
+        MapTile::NorthWest,
+        MapTile::NorthSouth,
+        MapTile::EastWest,
+        MapTile::SouthEast,
+        MapTile::SouthWest,
+    ];
+
+    /// the directions a pipe connects, `None` for tiles that are no pipe
+    fn connections(&self) -> Option<[Direction; 2]> {
+        use Direction::*;
+        Some(match self {
+            MapTile::NorthEast => [North, East],
+            MapTile::NorthWest => [North, West],
+            MapTile::NorthSouth => [North, South],
+            MapTile::EastWest => [East, West],
+            MapTile::SouthEast => [South, East],
+            MapTile::SouthWest => [South, West],
+            MapTile::Ground | MapTile::Start => return None,
+        })
+    }
+
+    fn connects(&self, dir: Direction) -> bool {
+        self.connections().is_some_and(|dirs| dirs.contains(&dir))
+    }
+}
+
+impl TryFrom<char> for MapTile {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            'S' => MapTile::Start,
+            '.' => MapTile::Ground,
+            '|' => MapTile::NorthSouth,
+            '-' => MapTile::EastWest,
+            'L' => MapTile::NorthEast,
+            'J' => MapTile::NorthWest,
+            '7' => MapTile::SouthWest,
+            'F' => MapTile::SouthEast,
+            c => return Err(c),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct FieldMap {
+    grid: Grid<MapTile>,
+    start_coord: Point,
+}
+
+impl FieldMap {
+    fn new(grid: Grid<MapTile>) -> Result<Self> {
+        let start_coord = grid
+            .position(|tile| tile == &MapTile::Start)
+            .context("The map has no start tile")?
+            .into();
+        Ok(FieldMap { grid, start_coord })
+    }
+
+    /// the tile at `pos`, `None` outside of the map
+    fn get(&self, pos: Point) -> Option<&MapTile> {
+        self.grid.at(pos)
+    }
+
+    /// follows the pipe at `cur` to the end that does not lead back to `prev`
+    fn get_next(&self, prev: Point, cur: Point) -> Result<Point> {
+        self.get_adjacent(cur)?
+            .into_iter()
+            .find(|&next| next != prev)
+            .context(format!("The pipe at {cur:?} leads back to {prev:?} twice"))
+    }
+
+    fn get_adjacent(&self, pos: Point) -> Result<[Point; 2]> {
+        let dirs = self
+            .get(pos)
+            .and_then(MapTile::connections)
+            .context(format!("The loop runs into {pos:?}, which is no pipe"))?;
+        Ok(dirs.map(|dir| pos.step(dir)))
+    }
+
+    /// the pipes the tile at `pos` is connected to, which have to connect back to it
+    fn connected(&self, pos: Point) -> Vec<Point> {
+        let dirs = match self.get(pos) {
+            Some(MapTile::Start) => self
+                .get_start_adjacents()
+                .map(Vec::from)
+                .unwrap_or_default(),
+            Some(tile) => tile.connections().map(Vec::from).unwrap_or_default(),
+            None => vec![],
+        };
+        dirs.into_iter()
+            .map(|dir| (dir, pos.step(dir)))
+            .filter(|&(dir, next)| {
+                self.get(next)
+                    .is_some_and(|tile| tile == &MapTile::Start || tile.connects(dir.opposite()))
+            })
+            .map(|(_, next)| next)
+            .collect()
+    }
+
+    /// every tile of the loop through the start tile, starting with it
+    fn main_loop(&self) -> Result<Vec<Point>> {
+        let mut main_loop = vec![self.start_coord];
+        let mut prev_coord = self.start_coord;
+        let mut cur_coord = self.start_coord.step(self.get_start_adjacents()?[0]);
+        while cur_coord != self.start_coord {
+            main_loop.push(cur_coord);
+            let new_coord = self.get_next(prev_coord, cur_coord)?;
+            prev_coord = cur_coord;
+            cur_coord = new_coord;
+        }
+        Ok(main_loop)
+    }
+
+    /// the pipe hidden below the start tile, derived from the pipes connecting to it
+    fn get_start_maptile(&self) -> Result<MapTile> {
+        let dirs = self.get_start_adjacents()?;
+        MapTile::PIPES
+            .into_iter()
+            .find(|pipe| dirs.iter().all(|&dir| pipe.connects(dir)))
+            .context(format!("No pipe connects {dirs:?}"))
+    }
+
+    /// the directions in which the neighbours of the start tile connect back to it
+    fn get_start_adjacents(&self) -> Result<[Direction; 2]> {
+        let start = self.start_coord;
+        let dirs: Vec<Direction> = Direction::ORTHOGONAL
+            .into_iter()
+            .filter(|&dir| {
+                self.get(start.step(dir))
+                    .is_some_and(|tile| tile.connects(dir.opposite()))
+            })
+            .collect();
+        ensure!(
+            dirs.len() == 2,
+            "{} pipes connect to the start tile, there have to be exactly 2",
+            dirs.len()
+        );
+        Ok([dirs[0], dirs[1]])
+    }
+}
+
+fn update_inside(tile: &MapTile, inside: bool) -> bool {
+    match tile {
+        MapTile::NorthWest | MapTile::SouthEast => inside,
+        MapTile::NorthEast | MapTile::NorthSouth | MapTile::EastWest | MapTile::SouthWest => {
+            !inside
+        }
+        t => panic!("Encountered unexpected tile type '{t:?}'"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    const SIMPLE_LOOP: &str = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+
+    const COMPLEX_LOOP: &str = "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ";
+
+    const ENCLOSED: &str = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+    const ENCLOSED_LARGER: &str = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(
+            Day10::solve(SIMPLE_LOOP, Part::One).unwrap().to_string(),
+            "4"
+        );
+        assert_eq!(
+            Day10::solve(COMPLEX_LOOP, Part::One).unwrap().to_string(),
+            "8"
+        );
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(Day10::solve(ENCLOSED, Part::Two).unwrap().to_string(), "4");
+        assert_eq!(
+            Day10::solve(ENCLOSED_LARGER, Part::Two)
+                .unwrap()
+                .to_string(),
+            "10"
+        );
+    }
+
+    #[test]
+    fn start_tile_is_derived_from_its_neighbours() {
+        assert_eq!(
+            Day10::parse(SIMPLE_LOOP)
+                .unwrap()
+                .get_start_maptile()
+                .unwrap(),
+            MapTile::SouthEast
+        );
+        assert_eq!(
+            Day10::parse(COMPLEX_LOOP)
+                .unwrap()
+                .get_start_maptile()
+                .unwrap(),
+            MapTile::SouthEast
+        );
+    }
+
+    #[test]
+    fn dangling_pipes_into_the_start_are_an_error() {
+        let input = "..........\n------S-7.\n......|.|.\n......L-J.";
+        for part in Part::ALL {
+            let err = Day10::solve(input, part).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "3 pipes connect to the start tile, there have to be exactly 2"
+            );
+        }
+        let field_map = Day10::parse(input).unwrap();
+        assert_eq!(field_map.connected(field_map.start_coord), []);
+    }
+
+    #[test]
+    fn pipes_leading_off_the_map_are_an_error() {
+        let field_map = Day10::parse("SJ\nLJ").unwrap();
+        let err = field_map.main_loop().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The loop runs into (-1, 1), which is no pipe"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use anyhow::Result;
+use utils::{geom::Point, grid::Grid, Answer, Solution};
+
+const EXPAND: i64 = 1000000;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Parsed<'a> = Grid<MapTile>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        input.parse()
+    }
+
+    fn part1(map_image: &Self::Parsed<'_>) -> Result<Answer> {
+        let map_image = MapImage::new(map_image);
+        Ok(sum_of_distances(&map_image.galaxy_pos).into())
+    }
+
+    fn part2(map_image: &Self::Parsed<'_>) -> Result<Answer> {
+        let chart = expand_coords(chart(map_image));
+        Ok(sum_of_distances(&chart).into())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MapTile {
+    Empty,
+    Galaxy,
+}
+
+impl TryFrom<char> for MapTile {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(MapTile::Empty),
+            '#' => Ok(MapTile::Galaxy),
+            c => Err(c),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct MapImage {
+    galaxy_pos: Vec<Point>,
+}
+
+impl MapImage {
+    fn new(galaxy_map: &Grid<MapTile>) -> Self {
+        MapImage {
+            galaxy_pos: chart(&expand(galaxy_map)),
+        }
+    }
+}
+
+/// doubles every empty row and column
+fn expand(galaxy_map: &Grid<MapTile>) -> Grid<MapTile> {
+    // columns are rows of the transposed map
+    expand_rows(&expand_rows(galaxy_map).transpose()).transpose()
+}
+
+fn expand_rows(galaxy_map: &Grid<MapTile>) -> Grid<MapTile> {
+    let rows = galaxy_map.iter_rows().flat_map(|row| {
+        let copies = if row.iter().all(|c| c == &MapTile::Empty) {
+            2
+        } else {
+            1
+        };
+        std::iter::repeat_n(row.to_vec(), copies)
+    });
+    Grid::from_rows(rows).expect("rows of a grid are equally long")
+}
+
+/// expands the space between the galaxies by moving their coordinates instead of inserting
+/// empty rows and columns into the map
+fn expand_coords(mut chart: Vec<Point>) -> Vec<Point> {
+    chart.sort();
+    // expand rows
+    let mut scan_idx = 1;
+    while scan_idx < chart.len() {
+        let row_diff = chart[scan_idx].row - chart[scan_idx - 1].row;
+        if row_diff > 1 {
+            for coord in chart[scan_idx..].iter_mut() {
+                coord.row += (EXPAND - 1) * (row_diff - 1);
+            }
+        }
+        scan_idx += 1;
+    }
+    chart.sort_by_key(|point| point.col);
+
+    scan_idx = 1;
+    while scan_idx < chart.len() {
+        let col_diff = chart[scan_idx].col - chart[scan_idx - 1].col;
+        if col_diff > 1 {
+            for coord in chart[scan_idx..].iter_mut() {
+                coord.col += (EXPAND - 1) * (col_diff - 1);
+            }
+        }
+        scan_idx += 1;
+    }
+    chart
+}
+
+fn chart(galaxy_map: &Grid<MapTile>) -> Vec<Point> {
+    galaxy_map
+        .iter()
+        .filter(|(_, tile)| tile == &&MapTile::Galaxy)
+        .map(|(pos, _)| pos.into())
+        .collect()
+}
+
+fn sum_of_distances(chart: &[Point]) -> u64 {
+    chart
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &orig)| chart[i + 1..].iter().map(move |&dest| orig.manhattan(dest)))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    const EXAMPLE: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day11::solve(EXAMPLE, Part::One).unwrap().to_string(), "374");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day11::solve(EXAMPLE, Part::Two).unwrap().to_string(),
+            "82000210"
+        );
+    }
+
+    #[test]
+    fn expand_doubles_empty_rows_and_columns() {
+        let galaxy_map = expand(&Day11::parse(EXAMPLE).unwrap());
+        assert_eq!(galaxy_map.rows(), 12);
+        assert_eq!(galaxy_map.cols(), 13);
+    }
+}
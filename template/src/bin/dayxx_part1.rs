@@ -1,10 +1,7 @@
 use anyhow::Result;
-use utils::get_input_file_as_string;
+use dayxx::Dayxx;
+use utils::{report, Part};
 
 fn main() -> Result<()> {
-    let data = get_input_file_as_string()?;
-
-    // all_inputs(&data).map_err(|err| err.to_owned())?;
-
-    Ok(())
+    report::run::<Dayxx>(Part::One)
 }
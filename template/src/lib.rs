@@ -0,0 +1,42 @@
+use anyhow::{bail, Result};
+use utils::{Answer, Solution};
+
+pub struct Dayxx;
+
+impl Solution for Dayxx {
+    const DAY: u8 = 0;
+
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        // e.g. utils::parse::complete(input, lines(numbers::<u64>))
+
+        Ok(input)
+    }
+
+    fn part1(_data: &Self::Parsed<'_>) -> Result<Answer> {
+        bail!("Part 1 is not solved yet")
+    }
+
+    fn part2(_data: &Self::Parsed<'_>) -> Result<Answer> {
+        bail!("Part 2 is not solved yet")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::Part;
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Dayxx::solve(EXAMPLE, Part::One).unwrap().to_string(), "");
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Dayxx::solve(EXAMPLE, Part::Two).unwrap().to_string(), "");
+    }
+}
@@ -0,0 +1,281 @@
+//! Finding the cycle every walk through a finite number of states ends up in.
+//!
+//! Step `t` of a walk is the state after applying the step function `t` times to the start,
+//! step 0 is the start itself.
+
+use std::collections::BTreeSet;
+
+use crate::math;
+
+/// How a walk runs into its cycle and when it meets its target
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cycle {
+    /// number of steps before the cycle is entered for the first time
+    pub prefix: usize,
+    pub length: usize,
+    /// the steps before the cycle at which the target is met
+    pub prefix_hits: Vec<usize>,
+    /// the steps of the first round through the cycle at which the target is met, i.e. in
+    /// `prefix..prefix + length`. They repeat every `length` steps.
+    pub hits: Vec<usize>,
+}
+
+impl Cycle {
+    /// whether the walk meets its target at `step`
+    pub fn hits_at(&self, step: usize) -> bool {
+        if step < self.prefix {
+            return self.prefix_hits.contains(&step);
+        }
+        let in_first_round = self.prefix + (step - self.prefix) % self.length;
+        self.hits.contains(&in_first_round)
+    }
+
+    /// the first step at which the walk meets its target, `None` if it never does
+    pub fn first_hit(&self) -> Option<usize> {
+        self.prefix_hits.first().or(self.hits.first()).copied()
+    }
+}
+
+/// Brent's algorithm, returns the prefix and cycle length of the walk from `start`
+pub fn brent<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> (usize, usize) {
+    // find the cycle length by letting the hare run ahead in growing powers of two
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // with the hare `length` steps ahead they meet where the cycle begins
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    (prefix, length)
+}
+
+/// Floyd's tortoise and hare, returns the prefix and cycle length of the walk from `start`
+pub fn floyd<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S) -> (usize, usize) {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let half_way = step(&hare);
+        hare = step(&half_way);
+    }
+
+    let mut prefix = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    (prefix, length)
+}
+
+/// Finds the cycle of the walk from `start` and every step at which `target` is met
+pub fn find<S: Clone + Eq>(
+    start: S,
+    mut step: impl FnMut(&S) -> S,
+    mut target: impl FnMut(&S) -> bool,
+) -> Cycle {
+    let (prefix, length) = brent(start.clone(), &mut step);
+    let mut cycle = Cycle {
+        prefix,
+        length,
+        ..Default::default()
+    };
+    let mut state = start;
+    for t in 0..prefix + length {
+        if target(&state) {
+            if t < prefix {
+                cycle.prefix_hits.push(t);
+            } else {
+                cycle.hits.push(t);
+            }
+        }
+        state = step(&state);
+    }
+    cycle
+}
+
+/// Like `find` for walks that are driven by a list of instructions that is repeated over and
+/// over, like "go left, go right, go right". Only the state and the position in the list
+/// together repeat, so a step is one instruction.
+pub fn find_with_instructions<S: Clone + Eq, I>(
+    start: S,
+    instructions: &[I],
+    mut step: impl FnMut(&S, &I) -> S,
+    mut target: impl FnMut(&S) -> bool,
+) -> Cycle {
+    assert!(!instructions.is_empty(), "Walks need instructions");
+    find(
+        (start, 0),
+        |(state, idx)| {
+            (
+                step(state, &instructions[*idx]),
+                (idx + 1) % instructions.len(),
+            )
+        },
+        |(state, _)| target(state),
+    )
+}
+
+/// The first step at which all walks meet their targets at the same time, `None` if that
+/// never happens
+pub fn first_common_hit(cycles: &[Cycle]) -> Option<u64> {
+    let start = cycles.iter().map(|c| c.prefix).max()?;
+
+    // all walks are inside their cycles from `start` on, before that hits are checked one by one
+    let early: BTreeSet<usize> = cycles
+        .iter()
+        .flat_map(|c| c.prefix_hits.iter().chain(&c.hits))
+        .copied()
+        .filter(|&t| t < start)
+        .collect();
+    if let Some(t) = early
+        .into_iter()
+        .find(|&t| cycles.iter().all(|c| c.hits_at(t)))
+    {
+        return Some(t as u64);
+    }
+
+    // from there on every choice of one hit per cycle is a system of congruences
+    let mut combinations: Vec<(i128, i128)> = vec![(0, 1)];
+    for cycle in cycles {
+        combinations = combinations
+            .into_iter()
+            .flat_map(|(rem, modulus)| {
+                cycle.hits.iter().filter_map(move |&hit| {
+                    math::crt([(rem, modulus), (hit as i128, cycle.length as i128)])
+                })
+            })
+            .collect();
+    }
+    combinations
+        .into_iter()
+        .map(|(rem, modulus)| {
+            // the smallest solution that is not before `start`
+            let start = start as i128;
+            if rem >= start {
+                rem
+            } else {
+                rem + (start - rem + modulus - 1) / modulus * modulus
+            }
+        })
+        .min()
+        .and_then(|t| u64::try_from(t).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 → 1 → 2 → 3 → 4 → 5 → 6 → 2 → …
+    fn step(x: &u32) -> u32 {
+        if *x == 6 {
+            2
+        } else {
+            x + 1
+        }
+    }
+
+    #[test]
+    fn brent_and_floyd_agree() {
+        assert_eq!(brent(0, step), (2, 5));
+        assert_eq!(floyd(0, step), (2, 5));
+        assert_eq!(brent(4, step), (0, 5));
+        assert_eq!(floyd(4, step), (0, 5));
+        // a fixpoint is a cycle of length 1
+        assert_eq!(brent(7, |&x| x), (0, 1));
+        assert_eq!(floyd(7, |&x| x), (0, 1));
+    }
+
+    #[test]
+    fn finds_hits_before_and_inside_the_cycle() {
+        let cycle = find(0, step, |&x| x % 3 == 0);
+        assert_eq!(
+            cycle,
+            Cycle {
+                prefix: 2,
+                length: 5,
+                prefix_hits: vec![0],
+                hits: vec![3, 6],
+            }
+        );
+        assert!(cycle.hits_at(8));
+        assert!(cycle.hits_at(11));
+        assert!(!cycle.hits_at(12));
+        assert_eq!(cycle.first_hit(), Some(0));
+        assert_eq!(find(1, step, |&x| x > 100).first_hit(), None);
+    }
+
+    #[test]
+    fn instruction_index_is_part_of_the_state() {
+        // the state alone is back at 0 after two steps, but the walk only repeats after four
+        let cycle = find_with_instructions(0, &[1, 1, 2, 2], |x, add| (x + add) % 2, |&x| x == 1);
+        assert_eq!(cycle.prefix, 0);
+        assert_eq!(cycle.length, 4);
+        assert_eq!(cycle.hits, [1]);
+    }
+
+    #[test]
+    fn combines_cycles_with_different_offsets() {
+        // hits at 2, 5, 8, … and at 1, 3, 5, …
+        let a = Cycle {
+            prefix: 1,
+            length: 3,
+            prefix_hits: vec![],
+            hits: vec![2],
+        };
+        let b = Cycle {
+            prefix: 1,
+            length: 2,
+            prefix_hits: vec![],
+            hits: vec![1],
+        };
+        assert_eq!(first_common_hit(&[a.clone(), b.clone()]), Some(5));
+
+        // even hits never meet odd ones
+        let c = Cycle {
+            prefix: 0,
+            length: 2,
+            prefix_hits: vec![],
+            hits: vec![0],
+        };
+        assert_eq!(first_common_hit(&[b, c.clone()]), None);
+
+        // meeting before all walks are in their cycles
+        let d = Cycle {
+            prefix: 10,
+            length: 4,
+            prefix_hits: vec![2],
+            hits: vec![11],
+        };
+        assert_eq!(first_common_hit(&[a, d.clone()]), Some(2));
+        assert_eq!(first_common_hit(&[c, d]), Some(2));
+        assert_eq!(first_common_hit(&[]), None);
+    }
+}
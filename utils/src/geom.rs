@@ -0,0 +1,303 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::grid::Pos;
+
+/// A position or offset on an unbounded 2D plane. Rows grow downwards, columns to the right,
+/// both may be negative.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { row: 0, col: 0 };
+
+    pub const fn new(row: i64, col: i64) -> Self {
+        Point { row, col }
+    }
+
+    /// the neighbour in direction `dir`
+    pub fn step(self, dir: Direction) -> Self {
+        self + dir.offset()
+    }
+
+    /// the neighbour in direction `dir` if it lies inside `bounds`
+    pub fn step_within(self, dir: Direction, bounds: &Bounds) -> Option<Self> {
+        let next = self.step(dir);
+        bounds.contains(next).then_some(next)
+    }
+
+    /// number of orthogonal steps between two points
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// number of steps between two points when diagonal steps are allowed
+    pub fn chebyshev(self, other: Self) -> u64 {
+        self.row
+            .abs_diff(other.row)
+            .max(self.col.abs_diff(other.col))
+    }
+}
+
+impl fmt::Debug for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((row, col): (i64, i64)) -> Self {
+        Point { row, col }
+    }
+}
+
+impl From<Point> for (i64, i64) {
+    fn from(point: Point) -> Self {
+        (point.row, point.col)
+    }
+}
+
+impl From<Pos> for Point {
+    fn from((row, col): Pos) -> Self {
+        Point {
+            row: row as i64,
+            col: col as i64,
+        }
+    }
+}
+
+/// Only points with both coordinates non-negative are grid positions
+impl TryFrom<Point> for Pos {
+    type Error = Point;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        match (usize::try_from(point.row), usize::try_from(point.col)) {
+            (Ok(row), Ok(col)) => Ok((row, col)),
+            _ => Err(point),
+        }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Self::Output {
+        Point::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Point::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Point::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+/// Compass directions with north pointing up, i.e. to smaller rows
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// all directions clockwise starting north
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// the four directions without the diagonals, clockwise starting north
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// position of the direction in `ALL`, 45° per step
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// the direction `eighths` times 45° clockwise from this one
+    fn rotate(self, eighths: usize) -> Self {
+        Self::ALL[(self.index() + eighths) % 8]
+    }
+
+    /// 90° counterclockwise
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// 90° clockwise
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// the offset of one step in this direction
+    pub fn offset(self) -> Point {
+        use Direction::*;
+        let (row, col) = match self {
+            North => (-1, 0),
+            NorthEast => (-1, 1),
+            East => (0, 1),
+            SouthEast => (1, 1),
+            South => (1, 0),
+            SouthWest => (1, -1),
+            West => (0, -1),
+            NorthWest => (-1, -1),
+        };
+        Point::new(row, col)
+    }
+}
+
+/// An axis aligned rectangle including its corners
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    pub fn new(min: Point, max: Point) -> Self {
+        Bounds { min, max }
+    }
+
+    /// the bounds of a grid with `rows` rows and `cols` columns starting at the origin
+    pub fn from_size(rows: usize, cols: usize) -> Self {
+        Bounds {
+            min: Point::ORIGIN,
+            max: Point::new(rows as i64 - 1, cols as i64 - 1),
+        }
+    }
+
+    /// the smallest bounds holding all `points`, `None` if there are none
+    pub fn enclosing(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        points.into_iter().fold(None, |bounds, p| {
+            Some(match bounds {
+                None => Bounds::new(p, p),
+                Some(Bounds { min, max }) => Bounds::new(
+                    Point::new(min.row.min(p.row), min.col.min(p.col)),
+                    Point::new(max.row.max(p.row), max.col.max(p.col)),
+                ),
+            })
+        })
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (self.min.row..=self.max.row).contains(&p.row)
+            && (self.min.col..=self.max.col).contains(&p.col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Direction::*;
+
+    #[test]
+    fn turns() {
+        assert_eq!(North.turn_right(), East);
+        assert_eq!(North.turn_left(), West);
+        assert_eq!(SouthWest.turn_left(), SouthEast);
+        assert_eq!(NorthWest.opposite(), SouthEast);
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.opposite().offset(), -dir.offset());
+            assert_eq!(
+                dir.is_diagonal(),
+                dir.offset().manhattan(Point::ORIGIN) == 2
+            );
+        }
+    }
+
+    #[test]
+    fn steps_stay_inside_bounds() {
+        let bounds = Bounds::from_size(3, 4);
+        let corner = Point::new(0, 0);
+        assert_eq!(corner.step(North), Point::new(-1, 0));
+        assert_eq!(corner.step_within(North, &bounds), None);
+        assert_eq!(corner.step_within(West, &bounds), None);
+        assert_eq!(
+            corner.step_within(SouthEast, &bounds),
+            Some(Point::new(1, 1))
+        );
+        assert_eq!(Point::new(2, 3).step_within(East, &bounds), None);
+        assert_eq!(
+            Bounds::enclosing([Point::new(2, -1), Point::new(-3, 4)]),
+            Some(Bounds::new(Point::new(-3, -1), Point::new(2, 4)))
+        );
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point::new(-2, 3);
+        let b = Point::new(4, 1);
+        assert_eq!(a.manhattan(b), 8);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(a.manhattan(a), 0);
+    }
+
+    #[test]
+    fn converts_from_and_to_grid_positions() {
+        assert_eq!(Point::from((2_usize, 5_usize)), Point::new(2, 5));
+        assert_eq!(Pos::try_from(Point::new(2, 5)), Ok((2, 5)));
+        assert!(Pos::try_from(Point::new(0, 0).step(West)).is_err());
+        assert_eq!(<(i64, i64)>::from(Point::new(-1, 7)), (-1, 7));
+    }
+}
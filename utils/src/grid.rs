@@ -0,0 +1,333 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use anyhow::{bail, Context, Error, Result};
+
+use crate::geom::{Bounds, Direction, Point};
+
+/// A position in a grid as `(row, col)`, counted from the top left
+pub type Pos = (usize, usize);
+
+/// A dense, rectangular 2D map stored row by row
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// builds a grid from its cells in reading order
+    pub fn new(rows: usize, cols: usize, cells: Vec<T>) -> Result<Self> {
+        if cells.len() != rows * cols {
+            bail!("{} cells do not make a grid of {rows}x{cols}", cells.len());
+        }
+        Ok(Grid { cells, rows, cols })
+    }
+
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// builds a grid from its rows, which all have to be equally long
+    pub fn from_rows<R>(rows: impl IntoIterator<Item = R>) -> Result<Self>
+    where
+        R: IntoIterator<Item = T>,
+    {
+        let mut cells = vec![];
+        let mut cols = None;
+        let mut row_count = 0;
+        for row in rows {
+            let len = cells.len();
+            cells.extend(row);
+            let width = cells.len() - len;
+            match cols {
+                None => cols = Some(width),
+                Some(cols) if cols != width => {
+                    bail!("Row {row_count} has {width} columns instead of {cols}")
+                }
+                Some(_) => {}
+            }
+            row_count += 1;
+        }
+        Self::new(row_count, cols.unwrap_or_default(), cells)
+    }
+
+    /// Parses one tile per character, every line is a row and line breaks at the end are
+    /// ignored. `tile` returns `None` for characters that are no valid tile.
+    pub fn parse(input: &str, mut tile: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let rows = input
+            .trim_end_matches(['\r', '\n'])
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| {
+                        tile(c).context(format!("Unknown tile '{c}' at ({row}, {col})"))
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::from_rows(rows)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.rows && col < self.cols
+    }
+
+    /// the corners of the grid as points, see `Point::step_within`
+    pub fn bounds(&self) -> Bounds {
+        Bounds::from_size(self.rows, self.cols)
+    }
+
+    /// the tile at `point`, `None` if it lies outside the grid
+    pub fn at(&self, point: Point) -> Option<&T> {
+        self.get(point.try_into().ok()?)
+    }
+
+    pub fn at_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.get_mut(point.try_into().ok()?)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.cols + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.0 * self.cols + pos.1])
+    }
+
+    /// every position in reading order
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    /// every tile with its position in reading order
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// the position of the first tile in reading order for which `pred` is true
+    pub fn position(&self, pred: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, t)| pred(t)).map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.rows).then(|| &self.cells[row * self.cols..(row + 1) * self.cols])
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks would panic on a grid without columns
+        (0..self.rows).map(|row| &self.cells[row * self.cols..(row + 1) * self.cols])
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let cols = self.cols;
+        self.cells
+            .iter()
+            .skip(col)
+            .step_by(cols.max(1))
+            .take(if col < cols { self.rows } else { 0 })
+    }
+
+    pub fn iter_cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(|col| self.column(col))
+    }
+
+    /// the orthogonal neighbours of `pos` that lie inside the grid, clockwise starting north
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &Direction::ORTHOGONAL)
+    }
+
+    /// all neighbours of `pos` including the diagonal ones that lie inside the grid, clockwise
+    /// starting north
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours(pos, &Direction::ALL)
+    }
+
+    fn neighbours<'a>(&'a self, pos: Pos, dirs: &'a [Direction]) -> impl Iterator<Item = Pos> + 'a {
+        let bounds = self.bounds();
+        dirs.iter()
+            .filter_map(move |&dir| Point::from(pos).step_within(dir, &bounds)?.try_into().ok())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// mirrors the grid along its main diagonal, rows become columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.cols, self.rows, |(row, col)| (col, row))
+    }
+
+    /// rotates the grid by 90° clockwise
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let rows = self.rows;
+        self.rebuild(self.cols, self.rows, |(row, col)| (rows - 1 - col, row))
+    }
+
+    /// rotates the grid by 90° counterclockwise
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cols = self.cols;
+        self.rebuild(self.cols, self.rows, |(row, col)| (col, cols - 1 - row))
+    }
+
+    /// a new grid of the given size, `source` tells which tile of `self` goes where
+    fn rebuild(&self, rows: usize, cols: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| (row, col)))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+        Grid { cells, rows, cols }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is outside of the {}x{} grid",
+                pos.0, pos.1, self.rows, self.cols
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let (rows, cols) = (self.rows, self.cols);
+        self.get_mut(pos).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is outside of the {rows}x{cols} grid",
+                pos.0, pos.1
+            )
+        })
+    }
+}
+
+/// Parses a grid with one tile per character, see `Grid::parse`
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s, |c| T::try_from(c).ok())
+    }
+}
+
+/// Prints the grid row by row without separators between the tiles
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.iter_rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for tile in row {
+                write!(f, "{tile}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Grid<char> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_and_prints() {
+        let g = grid("ab\ncd\nef");
+        assert_eq!((g.rows(), g.cols()), (3, 2));
+        assert_eq!(g.get((2, 1)), Some(&'f'));
+        assert_eq!(g.get((1, 2)), None);
+        assert_eq!(g.to_string(), "ab\ncd\nef");
+        assert_eq!(grid("ab\r\ncd\r\nef\r\n\n"), g);
+
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+        let err = Grid::parse("..\n.x", |c| (c == '.').then_some(())).unwrap_err();
+        assert_eq!(err.to_string(), "Unknown tile 'x' at (1, 1)");
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(g.row(2), None);
+        assert_eq!(g.column(1).collect::<String>(), "be");
+        assert_eq!(g.column(3).count(), 0);
+        assert_eq!(
+            g.iter_cols()
+                .map(|col| col.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(g.position(|&c| c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let g = grid("abc\ndef\nghi");
+        assert_eq!(g.neighbours4((0, 0)).collect::<Vec<_>>(), [(0, 1), (1, 0)]);
+        assert_eq!(g.neighbours4((1, 1)).count(), 4);
+        assert_eq!(
+            g.neighbours8((2, 2)).collect::<Vec<_>>(),
+            [(1, 2), (2, 1), (1, 1)]
+        );
+        assert_eq!(g.neighbours8((1, 1)).count(), 8);
+        assert_eq!(g.at(Point::new(-1, 0)), None);
+        assert_eq!(g.at(Point::new(2, 0)), Some(&'g'));
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(g.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(g.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(g.rotate_right().rotate_left(), g);
+        assert_eq!(g.transpose().transpose(), g);
+    }
+}
@@ -0,0 +1,370 @@
+use std::{
+    env, error, fmt, fs,
+    io::{self, Read},
+    ops::Deref,
+    path::{Path, PathBuf},
+};
+
+/// Everything that can go wrong while getting hold of the puzzle input
+#[derive(Debug)]
+pub enum InputError {
+    /// the command line could not be understood
+    InvalidArgument(String),
+    Missing(PathBuf),
+    NotAFile(PathBuf),
+    NotUtf8(PathBuf),
+    Empty(PathBuf),
+    /// any other I/O problem, e.g. missing permissions
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::InvalidArgument(msg) => write!(f, "{msg}"),
+            InputError::Missing(path) => write!(f, "Input file {} does not exist", path.display()),
+            InputError::NotAFile(path) => write!(f, "Input path {} is not a file", path.display()),
+            InputError::NotUtf8(path) => {
+                write!(f, "Input file {} is not valid UTF-8", path.display())
+            }
+            InputError::Empty(path) => write!(f, "Input file {} is empty", path.display()),
+            InputError::Io(path, err) => {
+                write!(f, "Could not read input file {}: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl error::Error for InputError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            InputError::Io(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl InputError {
+    fn from_io(path: &Path, err: io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::NotFound => InputError::Missing(path.to_owned()),
+            io::ErrorKind::InvalidData => InputError::NotUtf8(path.to_owned()),
+            _ => InputError::Io(path.to_owned(), err),
+        }
+    }
+}
+
+/// Where the puzzle input of a day is read from
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// `-` on the command line
+    Stdin,
+    Path(PathBuf),
+    /// `dayNN/example{N}`
+    Example(u8),
+    /// `dayNN/input`
+    #[default]
+    Default,
+}
+
+impl From<&str> for InputSource {
+    fn from(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+impl InputSource {
+    /// Reads the input source from the command line: a path or `-` for stdin as the first
+    /// positional argument, or `--example [N]` with N defaulting to 1. `--format <FORMAT>` is
+    /// skipped.
+    pub fn from_args() -> Result<Self, InputError> {
+        Self::parse_args(env::args().skip(1))
+    }
+
+    fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Self, InputError> {
+        let mut args = args.into_iter().peekable();
+        let mut sources = vec![];
+        while let Some(arg) = args.next() {
+            if arg == "--format" {
+                args.next();
+            } else if arg.starts_with("--format=") {
+                continue;
+            } else if arg == "--example" {
+                let nr = args.next_if(|next| next.parse::<u8>().is_ok());
+                sources.push(InputSource::Example(
+                    nr.and_then(|nr| nr.parse().ok()).unwrap_or(1),
+                ));
+            } else if let Some(nr) = arg.strip_prefix("--example=") {
+                let nr = nr.parse().map_err(|_| {
+                    InputError::InvalidArgument(format!("'{nr}' is not an example number"))
+                })?;
+                sources.push(InputSource::Example(nr));
+            } else if arg.starts_with("--") {
+                return Err(InputError::InvalidArgument(format!(
+                    "Unknown option '{arg}'"
+                )));
+            } else {
+                sources.push(InputSource::from(arg.as_str()));
+            }
+        }
+        match sources.len() {
+            0 | 1 => Ok(sources.pop().unwrap_or_default()),
+            _ => Err(InputError::InvalidArgument(format!(
+                "Only one input can be read, got {sources:?}"
+            ))),
+        }
+    }
+
+    /// the file the input of `day` is read from, `None` for stdin
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::Stdin => None,
+            InputSource::Path(path) => Some(path.clone()),
+            InputSource::Example(nr) => Some(day_dir(day).join(format!("example{nr}"))),
+            InputSource::Default => Some(day_dir(day).join("input")),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<Input, InputError> {
+        match self.path(day) {
+            Some(path) => read_input_file(path),
+            None => read_stdin(),
+        }
+    }
+}
+
+/// The directory of a day's crate, relative to the workspace root
+pub fn day_dir(day: u8) -> PathBuf {
+    PathBuf::from(format!("day{day:02}"))
+}
+
+/// A whole puzzle input, read in one go and kept in a single buffer that everything parsed
+/// from it borrows from. Dereferences to `str`.
+///
+/// The text is kept exactly as it was read. Parsers have to cope with `\r\n` and a trailing
+/// line break, like `str::lines` and `parse::complete` do, or opt in to
+/// `normalize_line_endings`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Input {
+    text: String,
+}
+
+impl Input {
+    fn from_bytes(bytes: Vec<u8>, path: &Path) -> Result<Self, InputError> {
+        let text = String::from_utf8(bytes).map_err(|_| InputError::NotUtf8(path.to_owned()))?;
+        if text.trim().is_empty() {
+            return Err(InputError::Empty(path.to_owned()));
+        }
+        Ok(Input { text })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.text.as_bytes()
+    }
+
+    /// Blocks of lines separated by one or more blank lines, without the line breaks around
+    /// them. Works on `\r\n` as well as `\n` line breaks.
+    pub fn paragraphs(&self) -> impl Iterator<Item = &str> {
+        let mut rest = self.text.as_str();
+        std::iter::from_fn(move || {
+            rest = rest.trim_start_matches(['\r', '\n']);
+            if rest.is_empty() {
+                return None;
+            }
+            let len: usize = rest
+                .split_inclusive('\n')
+                .take_while(|line| !line.trim_end_matches(['\r', '\n']).is_empty())
+                .map(str::len)
+                .sum();
+            let (paragraph, tail) = rest.split_at(len);
+            rest = tail;
+            Some(paragraph.trim_end_matches(['\r', '\n']))
+        })
+    }
+
+    /// Turns every `\r\n` into `\n` and drops the line breaks at the end, both in place
+    pub fn normalize_line_endings(&mut self) {
+        // only whole `\r\n` pairs are removed, so the text stays valid UTF-8
+        let mut bytes = std::mem::take(&mut self.text).into_bytes();
+        strip_carriage_returns(&mut bytes);
+        while bytes.last() == Some(&b'\n') {
+            bytes.pop();
+        }
+        self.text = String::from_utf8(bytes).expect("removing ASCII keeps UTF-8 valid");
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl AsRef<str> for Input {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
+
+/// turns every `\r\n` into `\n` without allocating
+fn strip_carriage_returns(bytes: &mut Vec<u8>) {
+    if !bytes.contains(&b'\r') {
+        return;
+    }
+    let mut len = 0;
+    for idx in 0..bytes.len() {
+        if bytes[idx] == b'\r' && bytes.get(idx + 1) == Some(&b'\n') {
+            continue;
+        }
+        bytes[len] = bytes[idx];
+        len += 1;
+    }
+    bytes.truncate(len);
+}
+
+pub fn read_stdin() -> Result<Input, InputError> {
+    let path = Path::new("<stdin>");
+    let mut content = vec![];
+    io::stdin()
+        .read_to_end(&mut content)
+        .map_err(|err| InputError::from_io(path, err))?;
+    Input::from_bytes(content, path)
+}
+
+/// Reads the file at `filename` in one go, see `Input`
+pub fn read_input_file<P>(filename: P) -> Result<Input, InputError>
+where
+    P: AsRef<Path>,
+{
+    let path = filename.as_ref();
+    let content = fs::read(checked_file(path)?).map_err(|err| InputError::from_io(path, err))?;
+    Input::from_bytes(content, path)
+}
+
+/// makes sure there is a regular file at `path` before it is opened
+fn checked_file(path: &Path) -> Result<&Path, InputError> {
+    let metadata = fs::metadata(path).map_err(|err| InputError::from_io(path, err))?;
+    if metadata.is_file() {
+        Ok(path)
+    } else {
+        Err(InputError::NotAFile(path.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_file(name: &str, content: &[u8]) -> PathBuf {
+        let path = env::temp_dir().join(format!("utils-input-{}-{name}", std::process::id()));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    fn args(args: &[&str]) -> Result<InputSource, InputError> {
+        InputSource::parse_args(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn resolves_input_sources_from_args() {
+        assert_eq!(args(&[]).unwrap(), InputSource::Default);
+        assert_eq!(args(&["-"]).unwrap(), InputSource::Stdin);
+        assert_eq!(
+            args(&["--format", "json", "day04/input"]).unwrap(),
+            InputSource::Path("day04/input".into())
+        );
+        assert_eq!(
+            args(&["--example", "2", "--format=tsv"]).unwrap(),
+            InputSource::Example(2)
+        );
+        assert_eq!(args(&["--example"]).unwrap(), InputSource::Example(1));
+        assert!(args(&["day04/input", "--example", "1"]).is_err());
+        assert!(args(&["--verbose"]).is_err());
+    }
+
+    #[test]
+    fn example_paths_live_in_the_day_directory() {
+        assert_eq!(
+            InputSource::Example(2).path(4),
+            Some(PathBuf::from("day04/example2"))
+        );
+        assert_eq!(
+            InputSource::Default.path(11),
+            Some(PathBuf::from("day11/input"))
+        );
+        assert_eq!(InputSource::Stdin.path(11), None);
+    }
+
+    #[test]
+    fn keeps_line_endings_unless_asked() {
+        let path = scratch_file("crlf", b"1abc2\r\ntreb7uchet\r\n");
+        let mut input = read_input_file(&path).unwrap();
+        fs::remove_file(path).unwrap();
+        assert_eq!(input.as_str(), "1abc2\r\ntreb7uchet\r\n");
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["1abc2", "treb7uchet"]);
+
+        input.normalize_line_endings();
+        assert_eq!(input.as_str(), "1abc2\ntreb7uchet");
+        assert_eq!(input.as_bytes().last(), Some(&b't'));
+        // a lone carriage return is no line break
+        let mut bytes = b"a\rb\r\n".to_vec();
+        strip_carriage_returns(&mut bytes);
+        assert_eq!(bytes, b"a\rb\n");
+    }
+
+    #[test]
+    fn iterates_paragraphs() {
+        let path = scratch_file(
+            "paragraphs",
+            b"seeds: 1 2\r\n\r\nmap:\r\n3 4\n5 6\n\n\n\nlast\n\n",
+        );
+        let mut input = read_input_file(&path).unwrap();
+        fs::remove_file(path).unwrap();
+        assert_eq!(
+            input.paragraphs().collect::<Vec<_>>(),
+            ["seeds: 1 2", "map:\r\n3 4\n5 6", "last"]
+        );
+
+        input.normalize_line_endings();
+        assert_eq!(
+            input.paragraphs().collect::<Vec<_>>(),
+            ["seeds: 1 2", "map:\n3 4\n5 6", "last"]
+        );
+    }
+
+    #[test]
+    fn reports_the_offending_path() {
+        let missing = env::temp_dir().join("utils-input-does-not-exist");
+        let err = read_input_file(&missing).unwrap_err();
+        assert!(matches!(err, InputError::Missing(_)));
+        assert!(err.to_string().contains("utils-input-does-not-exist"));
+
+        assert!(matches!(
+            read_input_file(env::temp_dir()),
+            Err(InputError::NotAFile(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_empty_and_binary_files() {
+        let empty = scratch_file("empty", b"\n\n");
+        assert!(matches!(read_input_file(&empty), Err(InputError::Empty(_))));
+        fs::remove_file(empty).unwrap();
+
+        let binary = scratch_file("binary", &[0xff, 0xfe, b'\n']);
+        assert!(matches!(
+            read_input_file(&binary),
+            Err(InputError::NotUtf8(_))
+        ));
+        fs::remove_file(binary).unwrap();
+    }
+}
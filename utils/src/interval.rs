@@ -0,0 +1,266 @@
+//! Half-open integer ranges and maps that move whole sets of them at once.
+
+use std::fmt;
+
+use anyhow::{bail, Result};
+
+/// The numbers from `start` up to but excluding `end`, empty if `end <= start`
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Self {
+        Interval { start, end }
+    }
+
+    /// the `len` numbers from `start` on
+    pub const fn with_len(start: i64, len: i64) -> Self {
+        Interval::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.start.abs_diff(self.end)
+        }
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        (self.start..self.end).contains(&x)
+    }
+
+    /// the numbers in both intervals, `None` if there are none
+    pub fn intersect(self, other: Interval) -> Option<Interval> {
+        let both = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!both.is_empty()).then_some(both)
+    }
+
+    /// the numbers below `at` and the ones from `at` on, each `None` if there are none
+    pub fn split_at(self, at: i64) -> (Option<Interval>, Option<Interval>) {
+        let below = Interval::new(self.start, self.end.min(at));
+        let above = Interval::new(self.start.max(at), self.end);
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+
+    /// every number moved by `offset`
+    pub fn shift(self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl fmt::Debug for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// Sorted, disjoint intervals. Overlapping and touching intervals are merged, empty ones dropped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet(Vec<Interval>);
+
+impl IntervalSet {
+    pub fn iter(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.0.iter().copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// number of numbers in the set
+    pub fn len(&self) -> u64 {
+        self.0.iter().map(Interval::len).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.0.first().map(|interval| interval.start)
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        self.0.iter().any(|interval| interval.contains(x))
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut intervals: Vec<Interval> = iter.into_iter().filter(|i| !i.is_empty()).collect();
+        intervals.sort_unstable();
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        IntervalSet(merged)
+    }
+}
+
+/// A piecewise shift: numbers inside one of the source intervals move by its offset, all other
+/// numbers stay where they are
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    /// sorted by source, sources don't overlap
+    rules: Vec<(Interval, i64)>,
+}
+
+impl RangeMap {
+    /// `rules` pair source intervals with the offset numbers in them move by
+    pub fn new(rules: impl IntoIterator<Item = (Interval, i64)>) -> Result<Self> {
+        let mut rules: Vec<(Interval, i64)> = rules
+            .into_iter()
+            .filter(|(source, _)| !source.is_empty())
+            .collect();
+        rules.sort_unstable();
+        if let Some(pair) = rules
+            .windows(2)
+            .find(|pair| pair[0].0.end > pair[1].0.start)
+        {
+            bail!("The sources {:?} and {:?} overlap", pair[0].0, pair[1].0);
+        }
+        Ok(RangeMap { rules })
+    }
+
+    pub fn map(&self, x: i64) -> i64 {
+        self.rules
+            .iter()
+            .find(|(source, _)| source.contains(x))
+            .map_or(x, |(_, offset)| x + offset)
+    }
+
+    /// maps every number of `set` at once by cutting its intervals at the borders of the sources
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        let mut mapped = vec![];
+        for mut rest in set.iter() {
+            for &(source, offset) in &self.rules {
+                if source.start >= rest.end {
+                    break;
+                }
+                let (before, from_source) = rest.split_at(source.start);
+                mapped.extend(before);
+                let Some(from_source) = from_source else {
+                    break;
+                };
+                let (inside, after) = from_source.split_at(source.end);
+                mapped.extend(inside.map(|inside| inside.shift(offset)));
+                match after {
+                    Some(after) => rest = after,
+                    None => {
+                        rest = Interval::default();
+                        break;
+                    }
+                }
+            }
+            mapped.push(rest);
+        }
+        mapped.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn splits_intersects_and_shifts() {
+        let i = Interval::new(3, 8);
+        assert_eq!(i.len(), 5);
+        assert!(i.contains(3) && !i.contains(8));
+        assert_eq!(i.intersect(Interval::new(6, 20)), Some(Interval::new(6, 8)));
+        assert_eq!(i.intersect(Interval::new(8, 20)), None);
+        assert_eq!(
+            i.split_at(5),
+            (Some(Interval::new(3, 5)), Some(Interval::new(5, 8)))
+        );
+        assert_eq!(i.split_at(3), (None, Some(i)));
+        assert_eq!(i.split_at(10), (Some(i), None));
+        assert_eq!(i.shift(-4), Interval::new(-1, 4));
+        assert_eq!(Interval::new(5, 2).len(), 0);
+        assert_eq!(format!("{:?}", Interval::with_len(79, 14)), "79..93");
+    }
+
+    #[test]
+    fn sets_merge_overlapping_intervals() {
+        let set: IntervalSet = [
+            Interval::new(10, 12),
+            Interval::new(0, 3),
+            Interval::new(3, 5),
+            Interval::new(11, 15),
+            Interval::new(7, 7),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [Interval::new(0, 5), Interval::new(10, 15)]
+        );
+        assert_eq!(set.len(), 10);
+        assert_eq!(set.min(), Some(0));
+    }
+
+    #[test]
+    fn maps_sets_piece_by_piece() {
+        // the seed-to-soil map of the 2023 day 5 example
+        let map = RangeMap::new([
+            (Interval::with_len(98, 2), 50 - 98),
+            (Interval::with_len(50, 48), 52 - 50),
+        ])
+        .unwrap();
+        assert_eq!(map.map(79), 81);
+        assert_eq!(map.map(99), 51);
+        assert_eq!(map.map(10), 10);
+
+        let set: IntervalSet = [Interval::new(40, 101), Interval::new(120, 130)]
+            .into_iter()
+            .collect();
+        // 50..98 moves up by 2 and 98..100 fills the gap it leaves
+        assert_eq!(map.map_set(&set), set);
+        let set: IntervalSet = [Interval::new(95, 99)].into_iter().collect();
+        assert_eq!(
+            map.map_set(&set).iter().collect::<Vec<_>>(),
+            [Interval::new(50, 51), Interval::new(97, 100)]
+        );
+        assert!(RangeMap::new([(Interval::new(0, 5), 1), (Interval::new(4, 6), 2)]).is_err());
+    }
+
+    fn intervals() -> impl Strategy<Value = Vec<Interval>> {
+        prop::collection::vec(
+            (-50_i64..50, 0_i64..20).prop_map(|(start, len)| Interval::with_len(start, len)),
+            0..5,
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn map_set_agrees_with_mapping_every_number(
+            set in intervals(),
+            sources in intervals(),
+            offsets in prop::collection::vec(-30_i64..30, 5),
+        ) {
+            // keep the sources apart by only using every other one of the sorted, merged set
+            let sources: IntervalSet = sources.into_iter().collect();
+            let map = RangeMap::new(sources.iter().step_by(2).zip(offsets)).unwrap();
+            let set: IntervalSet = set.into_iter().collect();
+
+            let mapped = map.map_set(&set);
+            let expected: IntervalSet = set
+                .iter()
+                .flat_map(|i| i.start..i.end)
+                .map(|x| Interval::with_len(map.map(x), 1))
+                .collect();
+            prop_assert_eq!(mapped, expected);
+        }
+    }
+}
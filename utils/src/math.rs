@@ -0,0 +1,152 @@
+/// Greatest common divisor, `gcd(0, 0)` is 0
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, `None` if it does not fit into a `u64`
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple of all `numbers`, 1 if there are none and `None` on overflow
+pub fn lcm_all(numbers: impl IntoIterator<Item = u64>) -> Option<u64> {
+    numbers.into_iter().try_fold(1, lcm)
+}
+
+/// Extended Euclid, returns `(g, x, y)` with `a * x + b * y = g` where `g` is the greatest
+/// common divisor of `a` and `b`. `g` is never negative.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, `None` if `a` and `m` are not coprime
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Solves x ≡ r (mod m) for all given `(r, m)` at once, the moduli don't need to be coprime.
+/// Returns the smallest non-negative solution and the modulus all solutions repeat with,
+/// `None` if the congruences contradict each other or the combined modulus overflows.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences.into_iter().try_fold((0, 1), |(a, m), (b, n)| {
+        if n <= 0 {
+            return None;
+        }
+        let (g, p, _) = extended_gcd(m, n);
+        let diff = b - a;
+        if diff % g != 0 {
+            return None;
+        }
+        let lcm = (m / g).checked_mul(n)?;
+        // a + m * k solves both for k ≡ diff / g * p (mod n / g)
+        let k = (diff / g % (n / g)).checked_mul(p % (n / g))? % (n / g);
+        let x = a.checked_add(m.checked_mul(k)?)?.rem_euclid(lcm);
+        Some((x, lcm))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn known_values() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm_all([]), Some(1));
+        assert_eq!(lcm_all([2, 3, 4, 5]), Some(60));
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 6), None);
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // 4 and 6 are not coprime but agree on the remainder modulo 2
+        assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(0, 4), (1, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+    }
+
+    proptest! {
+        #[test]
+        fn gcd_divides_and_lcm_is_multiple(a in 1..1_000_000_u64, b in 1..1_000_000_u64) {
+            let g = gcd(a, b);
+            prop_assert_eq!(a % g, 0);
+            prop_assert_eq!(b % g, 0);
+            let l = lcm(a, b).unwrap();
+            prop_assert_eq!(l % a, 0);
+            prop_assert_eq!(l % b, 0);
+            prop_assert_eq!(g * l, a * b);
+        }
+
+        #[test]
+        fn lcm_all_never_wraps(numbers in prop::collection::vec(1..u64::MAX, 0..6)) {
+            if let Some(l) = lcm_all(numbers.clone()) {
+                for n in numbers {
+                    prop_assert_eq!(l % n, 0);
+                }
+            }
+        }
+
+        #[test]
+        fn bezout_identity(a in -1_000_000_000_i128..1_000_000_000, b in -1_000_000_000_i128..1_000_000_000) {
+            let (g, x, y) = extended_gcd(a, b);
+            prop_assert_eq!(a * x + b * y, g);
+            prop_assert!(g >= 0);
+            prop_assert_eq!(g as u64, gcd(a.unsigned_abs() as u64, b.unsigned_abs() as u64));
+        }
+
+        #[test]
+        fn inverse_multiplies_to_one(a in -10_000_i128..10_000, m in 2_i128..10_000) {
+            match mod_inverse(a, m) {
+                Some(inv) => prop_assert_eq!((a * inv).rem_euclid(m), 1),
+                None => prop_assert_ne!(extended_gcd(a, m).0, 1),
+            }
+        }
+
+        #[test]
+        fn crt_solves_every_congruence(congruences in prop::collection::vec((0_i128..1000, 1_i128..1000), 1..5)) {
+            match crt(congruences.clone()) {
+                Some((x, m)) => {
+                    prop_assert!((0..m).contains(&x));
+                    for (r, n) in congruences {
+                        prop_assert_eq!(x.rem_euclid(n), r.rem_euclid(n));
+                        prop_assert_eq!(m % n, 0);
+                    }
+                }
+                // no solution below the product of the moduli means there is none at all
+                None => {
+                    let bound: i128 = congruences.iter().map(|(_, n)| n).product();
+                    prop_assert!((0..bound.min(100_000)).all(|x| congruences
+                        .iter()
+                        .any(|&(r, n)| x.rem_euclid(n) != r.rem_euclid(n))));
+                }
+            }
+        }
+    }
+}
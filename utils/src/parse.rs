@@ -0,0 +1,195 @@
+//! nom parsers for the shapes puzzle inputs keep coming in.
+//!
+//! Lists stay on one line, only `lines` and `blocks` go across line breaks.
+
+use std::str::FromStr;
+
+use anyhow::Result;
+use nom::{
+    bytes::complete::{is_not, tag},
+    character::complete::{alphanumeric1, char, digit1, line_ending, space0, space1},
+    combinator::{all_consuming, map_res, opt, recognize},
+    multi::{many0, many1, separated_list1},
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+    IResult, Parser,
+};
+
+use crate::grid::Grid;
+
+/// Runs `parser` on all of `input`, anything left over but line breaks is an error
+pub fn complete<'a, O>(
+    input: &'a str,
+    parser: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> Result<O> {
+    let (_, out) = all_consuming(terminated(parser, many0(line_ending)))(input)
+        .map_err(|err| err.to_owned())?;
+    Ok(out)
+}
+
+pub fn get_u64(input: &str) -> IResult<&str, u64> {
+    map_res(recognize(digit1), str::parse)(input)
+}
+
+/// a number with an optional minus sign
+pub fn get_num<NUM: FromStr>(input: &str) -> IResult<&str, NUM> {
+    map_res(recognize(tuple((opt(tag("-")), digit1))), NUM::from_str)(input)
+}
+
+/// numbers separated by spaces, like `41 48 -83  86`
+pub fn numbers<NUM: FromStr>(input: &str) -> IResult<&str, Vec<NUM>> {
+    separated_list1(space1, get_num)(input)
+}
+
+/// `key:` followed by whatever `values` parses, like `Time:   7  15  30`
+pub fn header<'a, O>(
+    key: &'a str,
+    values: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    preceded(tuple((tag(key), char(':'), space0)), values)
+}
+
+/// `name` with a number followed by a colon and whatever `values` parses, like
+/// `Card  3: 1 21 53`. Returns the number and the values.
+pub fn numbered<'a, O>(
+    name: &'a str,
+    values: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (u64, O)> {
+    pair(
+        delimited(pair(tag(name), space1), get_u64, pair(char(':'), space0)),
+        values,
+    )
+}
+
+/// one `line` after the other, separated by single line breaks
+pub fn lines<'a, O>(
+    line: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(line_ending, line)
+}
+
+/// blocks of lines separated by blank lines
+pub fn blocks<'a, O>(
+    block: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(pair(line_ending, many1(line_ending)), block)
+}
+
+/// A title line ending in a colon followed by its lines, like
+///
+/// ```text
+/// seed-to-soil map:
+/// 50 98 2
+/// 52 50 48
+/// ```
+pub fn labelled_block<'a, L, O>(
+    label: impl Parser<&'a str, L, nom::error::Error<&'a str>>,
+    line: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (L, Vec<O>)> {
+    pair(
+        terminated(label, tuple((char(':'), space0, line_ending))),
+        lines(line),
+    )
+}
+
+/// Lines up to the next blank line or the end as a grid with one tile per character, see
+/// `Grid::parse`
+pub fn char_grid<'a, T>(
+    mut tile: impl FnMut(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>> {
+    map_res(
+        recognize(separated_list1(line_ending, is_not("\r\n"))),
+        move |rows| Grid::parse(rows, &mut tile),
+    )
+}
+
+/// `name = (left, right)` with alphanumeric names
+pub fn mapping(input: &str) -> IResult<&str, (&str, (&str, &str))> {
+    separated_pair(
+        alphanumeric1,
+        tag(" = "),
+        delimited(
+            char('('),
+            separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+            char(')'),
+        ),
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_lists_and_headers() {
+        assert_eq!(
+            complete("Time:      7  15   30", header("Time", numbers::<u32>)).unwrap(),
+            [7, 15, 30]
+        );
+        assert_eq!(complete("0 -3 6", numbers::<i64>).unwrap(), [0, -3, 6]);
+        // lists end with the line
+        assert_eq!(numbers::<u8>("1 2\n3"), Ok(("\n3", vec![1, 2])));
+        assert_eq!(
+            complete(
+                "Card   3: 1 21 | 53",
+                numbered(
+                    "Card",
+                    separated_pair(numbers::<u8>, tag(" | "), numbers::<u8>)
+                )
+            )
+            .unwrap(),
+            (3, (vec![1, 21], vec![53]))
+        );
+        assert!(complete("1 2 x", numbers::<u8>).is_err());
+        // line breaks at the end are fine, Windows ones included
+        assert_eq!(
+            complete("1 2\r\n3\r\n", lines(numbers::<u8>)).unwrap(),
+            [vec![1, 2], vec![3]]
+        );
+    }
+
+    #[test]
+    fn blocks_of_lines() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\n\nsoil-to-fertilizer map:\n0 15 37";
+        let (seeds, maps) = complete(
+            input,
+            separated_pair(
+                header("seeds", numbers::<u64>),
+                pair(line_ending, line_ending),
+                blocks(labelled_block(is_not(":\n"), numbers::<u64>)),
+            ),
+        )
+        .unwrap();
+        assert_eq!(seeds, [79, 14]);
+        assert_eq!(maps.len(), 2);
+        assert_eq!(
+            maps[0],
+            ("seed-to-soil map", vec![vec![50, 98, 2], vec![52, 50, 48]])
+        );
+        assert_eq!(maps[1].1, [[0, 15, 37]]);
+    }
+
+    #[test]
+    fn grids_and_mappings() {
+        let tile = |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+        let (rest, grids) = blocks(char_grid(tile))("#.\n.#\n\n##\n##").unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(grids.len(), 2);
+        assert_eq!(grids[0].get((1, 1)), Some(&true));
+        assert!(char_grid(|c| (c == '.').then_some(()))("..\n.x").is_err());
+
+        assert_eq!(
+            complete("AAA = (BBB, 11Z)", mapping).unwrap(),
+            ("AAA", ("BBB", "11Z"))
+        );
+        assert_eq!(
+            complete("AAA = (BBB, CCC)\nBBB = (AAA, ZZZ)", lines(mapping))
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use anyhow::{bail, Result};
+use serde::Serialize;
+use std::{
+    env,
+    fmt::Write,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{Answer, InputSource, Part, Solution};
+
+/// How answers are written to stdout
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// aligned table for humans
+    #[default]
+    Plain,
+    /// one JSON object per answer
+    Json,
+    /// tab separated values with a header line
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "plain" => Ok(Format::Plain),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            f => bail!("Unknown format '{f}', use plain, json or tsv"),
+        }
+    }
+}
+
+impl Format {
+    /// Reads `--format <FORMAT>` or `--format=<FORMAT>` from the command line, plain if missing
+    pub fn from_args() -> Result<Self> {
+        let mut args = env::args().skip(1);
+        while let Some(arg) = args.next() {
+            if arg == "--format" {
+                match args.next() {
+                    Some(format) => return format.parse(),
+                    None => bail!("--format needs a value: plain, json or tsv"),
+                }
+            } else if let Some(format) = arg.strip_prefix("--format=") {
+                return format.parse();
+            }
+        }
+        Ok(Format::default())
+    }
+}
+
+/// One solved part of one day
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    #[serde(rename = "elapsed_ns", serialize_with = "as_nanos")]
+    pub elapsed: Duration,
+}
+
+/// serializes a duration as whole nanoseconds, for `#[serde(serialize_with)]`
+pub fn as_nanos<S: serde::Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u128(d.as_nanos())
+}
+
+impl Report {
+    /// solves `part` of the day `S` and measures how long parsing and solving took
+    pub fn solve<S: Solution>(input: &str, part: Part) -> Result<Self> {
+        let start = Instant::now();
+        let answer = S::solve(input, part)?;
+        Ok(Report {
+            day: S::DAY,
+            part,
+            answer,
+            elapsed: start.elapsed(),
+        })
+    }
+}
+
+/// Renders `reports` in the given format, every line ends with a newline
+pub fn render(reports: &[Report], format: Format) -> String {
+    let mut out = String::new();
+    match format {
+        Format::Plain => {
+            out.push_str("Day  Part        Time  Answer\n");
+            for r in reports {
+                let _ = writeln!(
+                    out,
+                    "{:>3}  {:>4}  {:>10.2?}  {}",
+                    r.day, r.part, r.elapsed, r.answer
+                );
+            }
+        }
+        Format::Json => {
+            for r in reports {
+                // a flat struct of numbers and strings always serializes
+                out.push_str(&serde_json::to_string(r).expect("Report is valid JSON"));
+                out.push('\n');
+            }
+        }
+        Format::Tsv => {
+            out.push_str("day\tpart\tanswer\telapsed_ns\n");
+            for r in reports {
+                let _ = writeln!(
+                    out,
+                    "{}\t{}\t{}\t{}",
+                    r.day,
+                    r.part,
+                    r.answer,
+                    r.elapsed.as_nanos()
+                );
+            }
+        }
+    }
+    out
+}
+
+pub fn print(reports: &[Report], format: Format) {
+    print!("{}", render(reports, format));
+}
+
+/// Entry point of the `dayNN_partN` binaries: solves `part` for the input selected on the command
+/// line (see `InputSource`) and prints the answer in the format selected with `--format`
+pub fn run<S: Solution>(part: Part) -> Result<()> {
+    let format = Format::from_args()?;
+    let data = InputSource::from_args()?.read(S::DAY)?;
+    print(&[Report::solve::<S>(&data, part)?], format);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> Report {
+        Report {
+            day: 7,
+            part: Part::Two,
+            answer: 5905_u64.into(),
+            elapsed: Duration::from_micros(12),
+        }
+    }
+
+    #[test]
+    fn renders_json_lines() {
+        assert_eq!(
+            render(&[report()], Format::Json),
+            "{\"day\":7,\"part\":2,\"answer\":\"5905\",\"elapsed_ns\":12000}\n"
+        );
+    }
+
+    #[test]
+    fn renders_tsv_with_header() {
+        assert_eq!(
+            render(&[report()], Format::Tsv),
+            "day\tpart\tanswer\telapsed_ns\n7\t2\t5905\t12000\n"
+        );
+    }
+}
@@ -0,0 +1,265 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// What a step between two nodes may cost, `Default` is taken as zero
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// A path found by `dijkstra` or `astar`, from the start to the goal including both
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+/// Every node reached by a search with its cost from the start and the node it was reached
+/// from
+#[derive(Clone, Debug)]
+pub struct Explored<N, C> {
+    start: N,
+    costs: HashMap<N, C>,
+    parents: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy + Ord> Explored<N, C> {
+    fn new(start: N, zero: C) -> Self {
+        Explored {
+            costs: HashMap::from([(start.clone(), zero)]),
+            start,
+            parents: HashMap::new(),
+        }
+    }
+
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.costs.contains_key(node)
+    }
+
+    /// number of nodes reached, including the start
+    pub fn len(&self) -> usize {
+        self.costs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.costs.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&N, C)> {
+        self.costs.iter().map(|(node, &cost)| (node, cost))
+    }
+
+    /// a node that is the most expensive to reach and what it costs
+    pub fn farthest(&self) -> Option<(&N, C)> {
+        self.iter().max_by_key(|&(_, cost)| cost)
+    }
+
+    /// the cheapest path from the start to `node`, `None` if it was not reached
+    pub fn path_to(&self, node: &N) -> Option<Path<N, C>> {
+        let cost = self.cost(node)?;
+        let mut nodes = vec![node.clone()];
+        let mut cur = node;
+        while cur != &self.start {
+            cur = &self.parents[cur];
+            nodes.push(cur.clone());
+        }
+        nodes.reverse();
+        Some(Path { nodes, cost })
+    }
+}
+
+/// Breadth-first search from `start`, the cost of a node is the number of steps to reach it
+pub fn bfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> Explored<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut explored = Explored::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, steps)) = queue.pop_front() {
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = explored.costs.entry(next.clone()) {
+                entry.insert(steps + 1);
+                explored.parents.insert(next.clone(), node.clone());
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    explored
+}
+
+/// Cheapest costs from `start` to every node that can be reached, costs must not be negative
+pub fn dijkstra_all<N, C, I>(start: N, neighbours: impl FnMut(&N) -> I) -> Explored<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    explore(start, neighbours, |_| C::default(), |_| false).0
+}
+
+/// The cheapest path from `start` to a node for which `goal` is true
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), goal)
+}
+
+/// The cheapest path from `start` to a node for which `goal` is true. `heuristic` estimates
+/// the cost from a node to the goal and must never overestimate it, otherwise the path found
+/// might not be the cheapest.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (explored, reached) = explore(start, neighbours, heuristic, goal);
+    explored.path_to(&reached?)
+}
+
+/// Expands nodes cheapest estimate first until one is a goal, returns everything seen so far
+/// and the goal if one was reached
+fn explore<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> (Explored<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut explored = Explored::new(start.clone(), C::default());
+    // nodes are kept in a list so that they don't need to be `Ord` to go into the heap
+    let mut nodes = vec![start.clone()];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    while let Some(Reverse((_, cost, idx))) = heap.pop() {
+        let node = nodes[idx].clone();
+        if explored.costs[&node] < cost {
+            // reached cheaper in the meantime
+            continue;
+        }
+        if goal(&node) {
+            return (explored, Some(node));
+        }
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if explored.cost(&next).is_some_and(|known| known <= next_cost) {
+                continue;
+            }
+            explored.costs.insert(next.clone(), next_cost);
+            explored.parents.insert(next.clone(), node.clone());
+            heap.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                nodes.len(),
+            )));
+            nodes.push(next);
+        }
+    }
+    (explored, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geom::{Direction, Point},
+        grid::Grid,
+    };
+
+    const MAZE: &str = "S.#.....
+.##.###.
+....#...
+.##...#G";
+
+    fn maze() -> Grid<char> {
+        MAZE.parse().unwrap()
+    }
+
+    fn open_neighbours(grid: &Grid<char>, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .map(move |dir| p.step(dir))
+            .filter(|&next| grid.at(next).is_some_and(|&c| c != '#'))
+    }
+
+    #[test]
+    fn bfs_finds_distances_and_paths() {
+        let grid = maze();
+        let explored = bfs(Point::new(0, 0), |&p| open_neighbours(&grid, p));
+        let goal = Point::new(3, 7);
+        assert_eq!(explored.cost(&goal), Some(12));
+        assert_eq!(explored.cost(&Point::new(0, 3)), Some(7));
+        assert_eq!(explored.farthest().map(|(_, steps)| steps), Some(12));
+        assert!(!explored.contains(&Point::new(0, 2)));
+
+        let path = explored.path_to(&goal).unwrap();
+        assert_eq!(path.nodes.len(), 13);
+        assert_eq!(path.nodes.first(), Some(&Point::new(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&goal));
+        assert!(path
+            .nodes
+            .windows(2)
+            .all(|step| step[0].manhattan(step[1]) == 1));
+    }
+
+    #[test]
+    fn dijkstra_takes_the_cheapest_way() {
+        // the direct way from a to b is the expensive one
+        let edges = |node: &char| -> Vec<(char, u32)> {
+            match node {
+                'a' => vec![('b', 7), ('c', 2)],
+                'b' => vec![('d', 1)],
+                'c' => vec![('e', 2), ('b', 3)],
+                'e' => vec![('d', 4)],
+                _ => vec![],
+            }
+        };
+        let path = dijkstra('a', edges, |&n| n == 'd').unwrap();
+        assert_eq!(path.nodes, ['a', 'c', 'b', 'd']);
+        assert_eq!(path.cost, 6);
+        assert_eq!(dijkstra('a', edges, |&n| n == 'x'), None);
+
+        let all = dijkstra_all('a', edges);
+        assert_eq!(all.len(), 5);
+        assert_eq!(all.cost(&'e'), Some(4));
+        assert_eq!(all.path_to(&'a').unwrap().nodes, ['a']);
+    }
+
+    #[test]
+    fn astar_agrees_with_bfs() {
+        let grid = maze();
+        let goal = Point::new(3, 7);
+        let path = astar(
+            Point::new(0, 0),
+            |&p| open_neighbours(&grid, p).map(|next| (next, 1)),
+            |p| p.manhattan(goal),
+            |&p| p == goal,
+        )
+        .unwrap();
+        assert_eq!(path.cost, 12);
+        assert_eq!(path.nodes.len(), 13);
+    }
+}
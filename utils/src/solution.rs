@@ -0,0 +1,103 @@
+use anyhow::{bail, Result};
+use serde::{Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+/// One of the two halves of a day's puzzle
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            p => bail!("'{p}' is not a valid part, use 1 or 2"),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(match self {
+            Part::One => 1,
+            Part::Two => 2,
+        })
+    }
+}
+
+/// The printable result of solving one part of a puzzle
+#[derive(Clone, PartialEq, Eq)]
+pub struct Answer(String);
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.0)
+    }
+}
+
+// print answers as they are, so `dbg!` shows `= 142` instead of `= Answer("142")`
+impl fmt::Debug for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+macro_rules! answer_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(u32, u64, usize, i32, i64, i128, String, &str);
+
+/// A day of the calendar. `parse` turns the raw puzzle input into whatever both parts work on,
+/// the parsed data may borrow from the input.
+pub trait Solution {
+    const DAY: u8;
+
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>>;
+
+    fn part1(data: &Self::Parsed<'_>) -> Result<Answer>;
+
+    fn part2(data: &Self::Parsed<'_>) -> Result<Answer>;
+
+    fn solve(input: &str, part: Part) -> Result<Answer> {
+        let data = Self::parse(input)?;
+        match part {
+            Part::One => Self::part1(&data),
+            Part::Two => Self::part2(&data),
+        }
+    }
+}